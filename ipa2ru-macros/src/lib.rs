@@ -0,0 +1,13 @@
+use proc_macro::{Literal, TokenStream, TokenTree};
+use syn::{parse_macro_input, LitStr};
+
+use ipa2ru::Ru;
+
+#[proc_macro]
+pub fn ipa(input: TokenStream) -> TokenStream {
+    let ipa = parse_macro_input!(input as LitStr);
+    match Ru::try_from_ipa(&ipa.value()) {
+        Ok(ru) => TokenTree::Literal(Literal::string(ru.as_str())).into(),
+        Err(error) => syn::Error::new(ipa.span(), format!("{}", error)).to_compile_error().into(),
+    }
+}
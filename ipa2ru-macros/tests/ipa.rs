@@ -0,0 +1,9 @@
+use ipa2ru_macros::ipa;
+
+const NA: &str = ipa!("nʲæ");
+
+#[test]
+fn test_ipa() {
+    assert_eq!(NA, "ня");
+    assert_eq!(ipa!("nʲæ nʲæn"), "ня нян");
+}
@@ -0,0 +1,141 @@
+use alloc::{format, string::String, vec::Vec};
+
+use proptest::collection::{self, SizeRange};
+use proptest::prelude::*;
+use proptest::sample::{select, Select};
+
+use crate::input::Separator;
+use crate::options::ClusterStrategy;
+use crate::ru::{Consonants, PalatalizedOnlyConsonants, Phoneme, PhonemeSeq, Vowels};
+
+const VOWELS: &[Vowels] = &[Vowels::A, Vowels::E, Vowels::I, Vowels::O, Vowels::U];
+
+const CONSONANTS: &[Consonants] = {
+    use Consonants::*;
+    &[P, B, F, V, K, G, T, D, W, X, S, Z, L, M, N, R, H, C, DX, DZ]
+};
+
+const PALATALIZED_ONLY_CONSONANTS: &[PalatalizedOnlyConsonants] = &[
+    PalatalizedOnlyConsonants::J,
+    PalatalizedOnlyConsonants::Q,
+];
+
+const MARKERS: &[Phoneme] = &[
+    Phoneme::ReducedVowel,
+    Phoneme::MorphemeBoundary,
+    Phoneme::Stress,
+    Phoneme::SyllableBoundary,
+    Phoneme::Capital,
+];
+
+const CLUSTER_STRATEGIES: &[ClusterStrategy] = &[
+    ClusterStrategy::Keep,
+    ClusterStrategy::EpentheticY,
+    ClusterStrategy::EpentheticE,
+    ClusterStrategy::Hyphen,
+];
+
+const SEPARATORS: &[Separator] = &[
+    Separator::Space,
+    Separator::Tab,
+    Separator::LineFeed,
+    Separator::CrLf,
+    Separator::NoBreakSpace,
+];
+
+const IPA_VOWELS: &[&str] = &[
+    "u", "ɯ", "ʉ", "ɨ", "y", "i", "o", "ɤ", "ɵ", "ɘ",
+    "ø", "e", "ə", "ʊ", "ʏ", "ɪ", "æ", "ɑ", "a", "ʌ",
+];
+
+const IPA_CONSONANTS: &[&str] = &["n", "m", "j", "p", "nʲ", "mʲ", "pʲ"];
+
+impl Arbitrary for Vowels {
+    type Parameters = ();
+    type Strategy = Select<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        select(VOWELS)
+    }
+}
+
+impl Arbitrary for Consonants {
+    type Parameters = ();
+    type Strategy = Select<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        select(CONSONANTS)
+    }
+}
+
+impl Arbitrary for PalatalizedOnlyConsonants {
+    type Parameters = ();
+    type Strategy = Select<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        select(PALATALIZED_ONLY_CONSONANTS)
+    }
+}
+
+impl Arbitrary for Phoneme {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop_oneof![
+            4 => any::<Vowels>().prop_map(|phoneme| Phoneme::Vowel { phoneme }),
+            4 => (any::<Consonants>(), any::<bool>())
+                .prop_map(|(phoneme, is_palatalized)| Phoneme::Consonant { phoneme, is_palatalized }),
+            1 => any::<PalatalizedOnlyConsonants>().prop_map(|phoneme| Phoneme::PalatalizedOnlyConsonant { phoneme }),
+            1 => select(MARKERS),
+            1 => select(CLUSTER_STRATEGIES).prop_map(|strategy| Phoneme::ClusterBreak { strategy }),
+            1 => select(SEPARATORS).prop_map(|separator| Phoneme::Probel { separator }),
+        ]
+            .boxed()
+    }
+}
+
+impl Arbitrary for PhonemeSeq {
+    type Parameters = SizeRange;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(size: SizeRange) -> Self::Strategy {
+        collection::vec(any::<Phoneme>(), size).prop_map(PhonemeSeq::from).boxed()
+    }
+}
+
+fn ipa_segment() -> impl Strategy<Value = String> {
+    prop_oneof![
+        select(IPA_CONSONANTS).prop_map(String::from),
+        (select(IPA_VOWELS), any::<bool>())
+            .prop_map(|(vowel, is_long)| format!("{}{}", vowel, if is_long { "ː" } else { "" })),
+    ]
+}
+
+pub fn ipa_word() -> impl Strategy<Value = String> {
+    collection::vec(ipa_segment(), 1..8).prop_map(|segments| segments.concat())
+}
+
+pub fn ipa() -> impl Strategy<Value = String> {
+    collection::vec(ipa_word(), 1..4).prop_map(|words: Vec<String>| words.join(" "))
+}
+
+#[cfg(test)]
+mod arbitrary_tests {
+    use super::*;
+    use crate::options::RuOptions;
+    use crate::ru::Ru;
+
+    proptest! {
+        #[test]
+        fn test_ipa_converts(ipa in ipa()) {
+            prop_assert!(Ru::try_from_ipa(&ipa).is_ok());
+        }
+
+        #[test]
+        fn test_seq_renders(seq in any::<PhonemeSeq>()) {
+            let len = seq.len();
+            prop_assert_eq!(Ru::from_seq(seq, RuOptions::default()).phonemes().len(), len);
+        }
+    }
+}
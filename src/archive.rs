@@ -0,0 +1,39 @@
+use alloc::{format, string::ToString};
+
+use rkyv::{AlignedVec, Deserialize, Infallible};
+
+use crate::error::Error;
+use crate::options::RuOptions;
+use crate::ru::{ArchivedPhonemeSeq, PhonemeSeq, Ru};
+
+pub fn to_bytes(seq: &PhonemeSeq) -> Result<AlignedVec, Error> {
+    rkyv::to_bytes::<_, 256>(seq).map_err(|error| Error::InvalidArchive { reason: format!("{:?}", error) })
+}
+
+pub fn access(bytes: &[u8]) -> Result<&ArchivedPhonemeSeq, Error> {
+    rkyv::check_archived_root::<PhonemeSeq>(bytes).map_err(|error| Error::InvalidArchive { reason: error.to_string() })
+}
+
+pub fn to_ru(archived: &ArchivedPhonemeSeq, options: RuOptions) -> Ru {
+    let seq: PhonemeSeq = archived.deserialize(&mut Infallible).unwrap_or_else(|never| match never {});
+    Ru::from_seq(seq, options)
+}
+
+#[cfg(test)]
+mod archive_tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let ru = Ru::try_from("nʲæ mʲæn").unwrap();
+        let bytes = to_bytes(ru.seq()).unwrap();
+        let archived = access(&bytes).unwrap();
+        assert_eq!(archived.phonemes().len(), ru.phonemes().len());
+        assert_eq!(to_ru(archived, RuOptions::default()).as_str(), "ня мян");
+    }
+
+    #[test]
+    fn test_invalid_bytes() {
+        assert!(matches!(access(&[0xff; 3]), Err(Error::InvalidArchive { reason: _ })));
+    }
+}
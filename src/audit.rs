@@ -0,0 +1,36 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AuditRule {
+    HardSign,
+    SilentJot,
+    SyllabicJot,
+    SoftSign,
+    ReducedVowel,
+}
+
+impl AuditRule {
+    pub fn is_lossy(self) -> bool {
+        matches!(self, Self::SilentJot | Self::ReducedVowel)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AuditEvent {
+    pub position: usize,
+    pub span: Range<usize>,
+    pub rule: AuditRule,
+    pub output: &'static str,
+    pub alternatives: Vec<&'static str>,
+}
+
+pub trait AuditSink {
+    fn record(&mut self, event: AuditEvent);
+}
+
+impl AuditSink for Vec<AuditEvent> {
+    fn record(&mut self, event: AuditEvent) {
+        self.push(event)
+    }
+}
@@ -0,0 +1,165 @@
+use alloc::borrow::ToOwned;
+
+use crate::error::Error;
+use crate::options::{
+    Affricates, ClusterRules, FinalDevoicing, Geminates, Hiatus, HushingSoftSign, Hyphenation,
+    InitialE, JotAfterConsonant, JotRules, NormalizationForm, Orthography, QRules, ReleaseModifiers,
+    RuOptions, StressMarks, TseVowel, VowelLength, VowelReduction, YoPolicy,
+};
+use crate::ru::Ru;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RuBuilder {
+    options: RuOptions,
+}
+
+impl RuBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn options(self, options: RuOptions) -> Self {
+        Self { options }
+    }
+
+    pub fn yo(mut self, yo: bool) -> Self {
+        self.options.yo_policy = if yo { YoPolicy::Yo } else { YoPolicy::Ye };
+        self
+    }
+
+    pub fn stress_marks(mut self, stress_marks: bool) -> Self {
+        self.options.stress_marks = if stress_marks { StressMarks::Acute } else { StressMarks::Omit };
+        self
+    }
+
+    pub fn geminates(mut self, geminates: bool) -> Self {
+        self.options.geminates = if geminates { Geminates::Double } else { Geminates::Single };
+        self
+    }
+
+    pub fn capitalize_words(mut self, capitalize_words: bool) -> Self {
+        self.options.capitalize_words = capitalize_words;
+        self
+    }
+
+    pub fn normalization(mut self, normalization: NormalizationForm) -> Self {
+        self.options.normalization = normalization;
+        self
+    }
+
+    pub fn release_modifiers(mut self, release_modifiers: ReleaseModifiers) -> Self {
+        self.options.release_modifiers = release_modifiers;
+        self
+    }
+
+    pub fn jot_after_consonant(mut self, jot_after_consonant: JotAfterConsonant) -> Self {
+        self.options.jot_after_consonant = jot_after_consonant;
+        self
+    }
+
+    pub fn jot_rules(mut self, jot_rules: JotRules) -> Self {
+        self.options.jot_rules = jot_rules;
+        self
+    }
+
+    pub fn q_rules(mut self, q_rules: QRules) -> Self {
+        self.options.q_rules = q_rules;
+        self
+    }
+
+    pub fn orthography(mut self, orthography: Orthography) -> Self {
+        self.options.orthography = orthography;
+        self
+    }
+
+    pub fn tse_vowel(mut self, tse_vowel: TseVowel) -> Self {
+        self.options.tse_vowel = tse_vowel;
+        self
+    }
+
+    pub fn cluster_rules(mut self, cluster_rules: ClusterRules) -> Self {
+        self.options.cluster_rules = cluster_rules;
+        self
+    }
+
+    pub fn vowel_reduction(mut self, vowel_reduction: VowelReduction) -> Self {
+        self.options.vowel_reduction = vowel_reduction;
+        self
+    }
+
+    pub fn hiatus(mut self, hiatus: Hiatus) -> Self {
+        self.options.hiatus = hiatus;
+        self
+    }
+
+    pub fn initial_e(mut self, initial_e: InitialE) -> Self {
+        self.options.initial_e = initial_e;
+        self
+    }
+
+    pub fn final_devoicing(mut self, final_devoicing: FinalDevoicing) -> Self {
+        self.options.final_devoicing = final_devoicing;
+        self
+    }
+
+    pub fn hushing_soft_sign(mut self, hushing_soft_sign: HushingSoftSign) -> Self {
+        self.options.hushing_soft_sign = hushing_soft_sign;
+        self
+    }
+
+    pub fn vowel_length(mut self, vowel_length: VowelLength) -> Self {
+        self.options.vowel_length = vowel_length;
+        self
+    }
+
+    pub fn hyphenation(mut self, hyphenation: Hyphenation) -> Self {
+        self.options.hyphenation = hyphenation;
+        self
+    }
+
+    pub fn affricates(mut self, affricates: Affricates) -> Self {
+        self.options.affricates = affricates;
+        self
+    }
+
+    pub fn override_symbol(mut self, ipa: &str, ru: &str) -> Self {
+        self.options.overrides.push((ipa.to_owned(), ru.to_owned()));
+        self
+    }
+
+    pub fn into_options(self) -> RuOptions {
+        self.options
+    }
+
+    pub fn build(&self, ipa_str: &str) -> Result<Ru, Error> {
+        Ru::try_from_ipa_with(ipa_str, self.options.clone())
+    }
+}
+
+#[cfg(test)]
+mod builder_tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let builder = Ru::builder().yo(false).stress_marks(true).vowel_length(VowelLength::Drop);
+        assert_eq!(builder.build("nʲˈo mʲæːu").map(|ru| ru.to_string()), Ok("не\u{301} мяу".to_owned()));
+        assert_eq!(builder.into_options(), RuOptions {
+            yo_policy: YoPolicy::Ye,
+            stress_marks: StressMarks::Acute,
+            vowel_length: VowelLength::Drop,
+            ..RuOptions::default()
+        });
+    }
+
+    #[test]
+    fn test_override_symbol() {
+        let builder = Ru::builder().override_symbol("θ", "т");
+        assert_eq!(builder.build("θæm").map(|ru| ru.to_string()), Ok("там".to_owned()));
+    }
+
+    #[test]
+    fn test_default_matches_try_from() {
+        assert!(RuBuilder::new().build("nʲæ").ok() == Ru::try_from("nʲæ").ok());
+    }
+}
@@ -0,0 +1,191 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use alloc::{borrow::ToOwned, format, string::String, sync::Arc, vec::Vec};
+
+use crate::error::Error;
+use crate::options::{Preset, RuOptions};
+use crate::ru::Ru;
+use crate::rule::RuRule;
+
+const FINGERPRINT_HEADER: &str = concat!("ipa2ru/", env!("CARGO_PKG_VERSION"));
+const RULES_VERSION: u32 = 1;
+
+pub fn convert(ipa_str: &str) -> Result<String, Error> {
+    Ru::try_from_ipa(ipa_str).map(Ru::into_string)
+}
+
+#[derive(Clone, Default)]
+struct Rules(Vec<Arc<dyn RuRule + Send + Sync>>);
+
+impl fmt::Debug for Rules {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "Rules({})", self.0.len())
+    }
+}
+
+impl PartialEq for Rules {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len() && self.0.iter().zip(&other.0).all(|(rule, other)| Arc::ptr_eq(rule, other))
+    }
+}
+
+impl Eq for Rules {}
+
+impl Hash for Rules {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.len().hash(state)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Converter {
+    options: RuOptions,
+    preset: Option<Preset>,
+    rules: Rules,
+}
+
+impl Converter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_options(options: RuOptions) -> Self {
+        Self { options, preset: None, rules: Rules::default() }
+    }
+
+    pub fn with_preset(preset: Preset) -> Self {
+        Self { options: RuOptions::preset(preset), preset: Some(preset), rules: Rules::default() }
+    }
+
+    pub fn with_rule<R: RuRule + Send + Sync + 'static>(mut self, rule: R) -> Self {
+        self.rules.0.push(Arc::new(rule));
+        self
+    }
+
+    pub fn options(&self) -> &RuOptions {
+        &self.options
+    }
+
+    pub fn preset(&self) -> Option<Preset> {
+        self.preset
+    }
+
+    pub fn overlay(&self, overlay: &[(&str, &str)]) -> Result<Self, Error> {
+        let mut converter = self.clone();
+        overlay.iter().try_for_each(|(key, value)| converter.options.set(key, value))?;
+        converter.options.validate()?;
+        Ok(converter)
+    }
+
+    pub fn convert(&self, ipa_str: &str) -> Result<Ru, Error> {
+        let rules = self.rules.0.iter().map(|rule| rule.as_ref() as &dyn RuRule).collect::<Vec<_>>();
+        Ru::try_from_ipa_with_rules(ipa_str, self.options.clone(), &rules)
+    }
+
+    pub fn fingerprint(&self) -> Result<String, Error> {
+        if !self.rules.0.is_empty() {
+            return Err(Error::InvalidFingerprint { field: "rules".to_owned() });
+        }
+        Ok(self.options.fields().iter().fold(
+            format!("{};rules={};preset={}", FINGERPRINT_HEADER, RULES_VERSION, self.preset.map_or("none", Preset::name)),
+            |mut fingerprint, (key, value, _)| {
+                fingerprint.push_str(&format!(";{}={}", key, value));
+                fingerprint
+            }
+        ))
+    }
+
+    pub fn from_fingerprint(fingerprint: &str) -> Result<Self, Error> {
+        let invalid = |field: &str| Error::InvalidFingerprint { field: field.to_owned() };
+        let mut fields = fingerprint.split(';');
+        if fields.next() != Some(FINGERPRINT_HEADER) {
+            return Err(invalid("version"));
+        }
+        if fields.next() != Some(&format!("rules={}", RULES_VERSION)) {
+            return Err(invalid("rules"));
+        }
+        let preset = match fields.next().and_then(|field| field.strip_prefix("preset=")) {
+            Some("none") => None,
+            Some(name) => Some(Preset::from_name(name).ok_or_else(|| invalid("preset"))?),
+            None => return Err(invalid("preset")),
+        };
+        fields.try_fold(Self { preset, ..Self::new() }, |mut converter, field| {
+            let (key, value) = field.split_once('=').ok_or_else(|| invalid(field))?;
+            converter.options.set_field(key, value).ok_or_else(|| invalid(key))?;
+            Ok(converter)
+        })
+    }
+}
+
+#[cfg(test)]
+mod converter_tests {
+    use super::*;
+    use crate::options::{Geminates, StressMarks};
+    use crate::ru::{Phoneme, PhonemeSeq, Vowels};
+
+    #[test]
+    fn test_convert_fn() {
+        assert_eq!(convert("nʲæ mʲæːu"), Ok("ня мяау".to_owned()));
+        assert!(matches!(convert("nʲæ?"), Err(Error::UnsupportedSymbol { offset: 5, char_offset: 3, symbol: _ })));
+    }
+
+    #[test]
+    fn test_fingerprint_round_trip() {
+        let converter = Converter::with_options(RuOptions {
+            geminates: Geminates::Single,
+            stress_marks: StressMarks::Acute,
+            overrides: vec![("θ".to_owned(), "т;ц".to_owned()), ("a=b".to_owned(), ">".to_owned())],
+            ..RuOptions::default()
+        });
+        let fingerprint = converter.fingerprint().unwrap();
+        assert!(fingerprint.starts_with(FINGERPRINT_HEADER));
+        assert!(fingerprint.contains(";geminates=single;"));
+        assert!(fingerprint.ends_with(";override=θ>т%3Bц;override=a%3Db>%3E"));
+        assert_eq!(Converter::from_fingerprint(&fingerprint), Ok(converter));
+    }
+
+    #[test]
+    fn test_fingerprint_preset() {
+        let converter = Converter::with_preset(Preset::German);
+        let fingerprint = converter.fingerprint().unwrap();
+        assert!(fingerprint.contains(";preset=german;"));
+        assert!(fingerprint.contains(";override=ʃ>ш;"));
+        assert_eq!(Converter::from_fingerprint(&fingerprint), Ok(converter));
+        assert!(Converter::new().fingerprint().unwrap().contains(";preset=none;"));
+        assert_eq!(
+            Converter::from_fingerprint(&fingerprint.replace("preset=german", "preset=klingon")),
+            Err(Error::InvalidFingerprint { field: "preset".to_owned() })
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_mismatch() {
+        let fingerprint = Converter::new().fingerprint().unwrap();
+        assert_eq!(
+            Converter::from_fingerprint(&fingerprint.replace(FINGERPRINT_HEADER, "ipa2ru/0.0.0")),
+            Err(Error::InvalidFingerprint { field: "version".to_owned() })
+        );
+        assert_eq!(
+            Converter::from_fingerprint(&fingerprint.replace("hiatus=keep", "hiatus=glide")),
+            Err(Error::InvalidFingerprint { field: "hiatus".to_owned() })
+        );
+    }
+
+    #[test]
+    fn test_convert() {
+        assert_eq!(Converter::new().convert("nʲæ").map(|ru| ru.to_string()), Ok("ня".to_owned()));
+    }
+
+    #[test]
+    fn test_with_rule() {
+        let converter = Converter::new()
+            .with_rule(|seq: &mut PhonemeSeq| {
+                seq.phonemes_mut().pop();
+            })
+            .with_rule(|seq: &mut PhonemeSeq| seq.phonemes_mut().push(Phoneme::Vowel { phoneme: Vowels::O }));
+        assert_eq!(converter.convert("nʲæ").map(|ru| ru.to_string()), Ok("нё".to_owned()));
+        assert_eq!(converter.clone(), converter);
+        assert_ne!(converter, Converter::new());
+        assert_eq!(converter.fingerprint(), Err(Error::InvalidFingerprint { field: "rules".to_owned() }));
+    }
+}
@@ -0,0 +1,184 @@
+use alloc::{format, string::{String, ToString}, vec::Vec};
+
+use crate::audit::AuditEvent;
+use crate::options::RuOptions;
+use crate::ru::Ru;
+
+const SIP_INIT: [u64; 4] = [0x736f_6d65_7073_6575, 0x646f_7261_6e64_6f6d, 0x6c79_6765_6e65_7261, 0x7465_6462_7974_6573];
+
+fn sip_round(v: &mut [u64; 4]) {
+    v[0] = v[0].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(13) ^ v[0];
+    v[0] = v[0].rotate_left(32);
+    v[2] = v[2].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(16) ^ v[2];
+    v[0] = v[0].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(21) ^ v[0];
+    v[2] = v[2].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(17) ^ v[2];
+    v[2] = v[2].rotate_left(32);
+}
+
+fn le_word(bytes: &[u8]) -> u64 {
+    bytes.iter().rev().fold(0, |word, byte| word << 8 | u64::from(*byte))
+}
+
+fn siphash(key: &[u8; 16], data: &[u8]) -> u64 {
+    let (k0, k1) = (le_word(&key[..8]), le_word(&key[8..]));
+    let mut v = [SIP_INIT[0] ^ k0, SIP_INIT[1] ^ k1, SIP_INIT[2] ^ k0, SIP_INIT[3] ^ k1];
+    let chunks = data.chunks_exact(8);
+    let last = le_word(chunks.remainder()) | (data.len() as u64) << 56;
+    chunks.map(le_word).chain([last]).for_each(|word| {
+        v[3] ^= word;
+        (0..2).for_each(|_| sip_round(&mut v));
+        v[0] ^= word;
+    });
+    v[2] ^= 0xff;
+    (0..4).for_each(|_| sip_round(&mut v));
+    v[0] ^ v[1] ^ v[2] ^ v[3]
+}
+
+pub fn anonymize_tsv(corpus: &str, key: &[u8; 16]) -> String {
+    corpus
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut fields = line.split('\t');
+            let (word, ipa) = match (fields.next().unwrap_or_default(), fields.next()) {
+                (word, Some(ipa)) => (format!("{:016x}", siphash(key, word.as_bytes())), ipa),
+                (ipa, None) => (String::new(), ipa),
+            };
+            let ru = Ru::try_from(ipa).map(|ru| ru.to_string()).unwrap_or_default();
+            format!("{}\t{}\t{}\n", word, ipa, ru)
+        })
+        .collect()
+}
+
+const REPORT_HEAD: &str = "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>ipa2ru report</title>
+<style>
+table { border-collapse: collapse; }
+th, td { border: 1px solid #ccc; padding: 4px 8px; }
+.ok { background: #e6f4ea; }
+.review { background: #fef7e0; }
+.low { background: #fde0c5; }
+.error { background: #fce8e6; }
+</style>
+</head>
+<body>
+<table>
+<tr><th>Word</th><th>IPA</th><th>Cyrillic</th><th>Confidence</th><th>Warnings</th></tr>
+";
+
+const REPORT_TAIL: &str = "</table>
+</body>
+</html>
+";
+
+fn escape_html(text: &str) -> String {
+    text.chars().fold(String::with_capacity(text.len()), |mut escaped, symbol| {
+        match symbol {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _   => escaped.push(symbol),
+        }
+        escaped
+    })
+}
+
+fn confidence_class(confidence: f32) -> &'static str {
+    match confidence {
+        _ if confidence >= 1.0 => "ok",
+        _ if confidence >= 0.5 => "review",
+        _                      => "low",
+    }
+}
+
+fn report_row(word: &str, ipa: &str, options: &RuOptions) -> String {
+    let mut events: Vec<AuditEvent> = Vec::new();
+    let converted = Ru::try_from_ipa_with(ipa, options.clone())
+        .and_then(|ru| Ok((ru.render_audited(&mut events)?, ru.confidence())));
+    let source = |event: &AuditEvent| ipa.get(event.span.clone()).unwrap_or_default();
+    let (class, ru, confidence, warnings) = match converted {
+        Ok((ru, confidence)) => (
+            confidence_class(confidence),
+            ru,
+            format!("{:.2}", confidence),
+            events.iter()
+                .map(|event| format!("{:?} at {:?} ({}): {}", event.rule, event.span, source(event), event.output))
+                .collect::<Vec<_>>()
+                .join("; "),
+        ),
+        Err(error) => ("error", String::new(), String::new(), error.to_string()),
+    };
+    format!(
+        "<tr class=\"{}\"><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+        class, escape_html(word), escape_html(ipa), escape_html(&ru), confidence, escape_html(&warnings)
+    )
+}
+
+pub fn html_report(corpus: &str, options: &RuOptions) -> String {
+    let rows = corpus
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut fields = line.split('\t');
+            match (fields.next().unwrap_or_default(), fields.next()) {
+                (word, Some(ipa)) => report_row(word, ipa, options),
+                (ipa, None) => report_row("", ipa, options),
+            }
+        })
+        .collect::<String>();
+    format!("{}{}{}", REPORT_HEAD, rows, REPORT_TAIL)
+}
+
+#[cfg(test)]
+mod corpus_tests {
+    use super::*;
+
+    const KEY: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+    #[test]
+    fn test_anonymize_tsv() {
+        let corpus = "# source: internal lexicon v3\nnyan\tnʲæn\tnoun\tfreq=12\n\nbroken\t?\nmʲæːu\n";
+        assert_eq!(
+            anonymize_tsv(corpus, &KEY),
+            format!(
+                "{:016x}\tnʲæn\tнян\n{:016x}\t?\t\n\tmʲæːu\tмяау\n",
+                siphash(&KEY, b"nyan"),
+                siphash(&KEY, b"broken")
+            )
+        );
+        assert_ne!(anonymize_tsv(corpus, &KEY), anonymize_tsv(corpus, &[0; 16]));
+    }
+
+    #[test]
+    fn test_html_report() {
+        let report = html_report("# header\nnyan\tnʲæn\npjæ\nnʲʊ\n<b>\t?\n", &RuOptions::default());
+        assert!(report.starts_with(REPORT_HEAD) && report.ends_with(REPORT_TAIL));
+        assert!(report.contains("<tr class=\"ok\"><td>nyan</td><td>nʲæn</td><td>нян</td><td>1.00</td><td></td></tr>"));
+        let pja = "<tr class=\"ok\"><td></td><td>pjæ</td><td>пъя</td><td>1.00</td><td>HardSign at 1..2 (j): ъ</td></tr>";
+        assert!(report.contains(pja));
+        assert!(report.contains("<tr class=\"review\"><td></td><td>nʲʊ</td><td>ню</td><td>0.50</td><td></td></tr>"));
+        assert!(report.contains("<tr class=\"error\"><td>&lt;b&gt;</td><td>?</td>"));
+        assert!(!report.contains("# header"));
+    }
+
+    #[test]
+    fn test_hash_is_stable() {
+        assert_eq!(siphash(&KEY, b""), 0x726f_db47_dd0e_0e31);
+        assert_eq!(siphash(&KEY, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14]), 0xa129_ca61_49be_45e5);
+    }
+
+    #[test]
+    fn test_confidence_class() {
+        assert_eq!(confidence_class(1.0), "ok");
+        assert_eq!(confidence_class(0.5), "review");
+        assert_eq!(confidence_class(0.25), "low");
+    }
+}
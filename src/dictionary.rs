@@ -0,0 +1,130 @@
+use alloc::{collections::BTreeSet, format, string::String, vec, vec::Vec};
+
+use crate::ru::Ru;
+
+const CANDIDATE_LIMIT: usize = 8;
+
+const CONSONANT_LETTERS: &str = "бвгджзклмнпрстфхцчшщ";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Dictionary {
+    words: BTreeSet<String>,
+}
+
+impl Dictionary {
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self { words: words.into_iter().map(|word| word.as_ref().trim().to_lowercase()).collect() }
+    }
+
+    pub fn from_word_list(list: &str) -> Self {
+        Self::new(list.lines().filter(|line| !line.trim().is_empty() && !line.starts_with('#')))
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(&word.to_lowercase())
+    }
+
+    pub fn respell(&self, ru: &Ru) -> String {
+        let mut words = ru.words();
+        let mut respelled = String::with_capacity(ru.as_str().len());
+        let mut rest = ru.as_str();
+        while !rest.is_empty() {
+            let (word, tail) = rest.split_at(rest.find(char::is_whitespace).unwrap_or(rest.len()));
+            if !word.is_empty() {
+                match words.next().and_then(|ru_word| self.lookup(ru_word.ru())) {
+                    Some(known) => respelled.push_str(&with_casing(&known, word)),
+                    None => respelled.push_str(word),
+                }
+            }
+            let (space, tail) = tail.split_at(tail.find(|symbol: char| !symbol.is_whitespace()).unwrap_or(tail.len()));
+            respelled.push_str(space);
+            rest = tail;
+        }
+        respelled
+    }
+
+    fn lookup(&self, word: &Ru) -> Option<String> {
+        word.candidates(CANDIDATE_LIMIT)
+            .iter()
+            .flat_map(|candidate| variants(candidate))
+            .find(|variant| self.contains(variant))
+    }
+}
+
+fn with_casing(spelling: &str, original: &str) -> String {
+    let mut cases = original.chars().map(char::is_uppercase);
+    let mut is_upper = false;
+    spelling.chars().fold(String::with_capacity(spelling.len()), |mut cased, symbol| {
+        is_upper = cases.next().unwrap_or(is_upper);
+        match is_upper {
+            true => cased.extend(symbol.to_uppercase()),
+            false => cased.extend(symbol.to_lowercase()),
+        }
+        cased
+    })
+}
+
+fn variants(spelling: &str) -> Vec<String> {
+    let mut variants = vec![String::from(spelling)];
+    variants.extend(spelling.char_indices().filter_map(|(i, symbol)| {
+        let swapped = match symbol {
+            'е' => 'э',
+            'э' => 'е',
+            'Е' => 'Э',
+            'Э' => 'Е',
+            _   => return None,
+        };
+        let mut variant = String::from(spelling);
+        variant.replace_range(i..i + symbol.len_utf8(), swapped.encode_utf8(&mut [0; 4]));
+        Some(variant)
+    }));
+    let is_consonant = |symbol: char| symbol.to_lowercase().any(|lower| CONSONANT_LETTERS.contains(lower));
+    match spelling.strip_suffix('ь') {
+        Some(hard) => variants.push(String::from(hard)),
+        None if spelling.chars().last().is_some_and(is_consonant) => variants.push(format!("{}ь", spelling)),
+        None => {},
+    }
+    variants
+}
+
+#[cfg(test)]
+mod dictionary_tests {
+    use super::*;
+
+    #[test]
+    fn test_respell() {
+        let dictionary = Dictionary::from_word_list("# loanwords\nне\nНянь\n");
+        assert_eq!(dictionary.len(), 2);
+        let ru = Ru::try_from("ne  pæn\tnʲæn").unwrap();
+        assert_eq!(ru.as_str(), "нэ  пан\tнян");
+        assert_eq!(dictionary.respell(&ru), "не  пан\tнянь");
+        let ru = Ru::try_from("^ne ^nʲæn").unwrap();
+        assert_eq!(ru.as_str(), "Нэ Нян");
+        assert_eq!(dictionary.respell(&ru), "Не Нянь");
+    }
+
+    #[test]
+    fn test_with_casing() {
+        assert_eq!(with_casing("нянь", "НЯН"), "НЯНЬ");
+        assert_eq!(with_casing("Нянь", "нян"), "нянь");
+        assert_eq!(with_casing("нян", "НяНЬ"), "НяН");
+    }
+
+    #[test]
+    fn test_variants() {
+        assert_eq!(variants("эмь"), vec!["эмь".to_owned(), "емь".to_owned(), "эм".to_owned()]);
+        assert_eq!(variants("па"), vec!["па".to_owned()]);
+    }
+}
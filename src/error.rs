@@ -0,0 +1,105 @@
+use core::fmt;
+use alloc::string::String;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    ParseError { input: String, reason: String },
+    InvalidUtf8 { offset: usize },
+    UnknownSymbol { offset: usize, symbol: char },
+    UnsupportedSymbol { offset: usize, char_offset: usize, symbol: String },
+    UnsupportedCombination { offset: usize, char_offset: usize, symbols: String },
+    OptionConflict { first: &'static str, second: &'static str },
+    InvalidArchive { reason: String },
+    InvalidOptions { reason: String },
+    Render(RenderError),
+    InvalidFingerprint { field: String },
+}
+
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::ParseError { input: _, reason: _ } => "parse",
+            Self::InvalidUtf8 { offset: _ } => "invalid-utf8",
+            Self::UnknownSymbol { offset: _, symbol: _ } => "unknown-symbol",
+            Self::UnsupportedSymbol { offset: _, char_offset: _, symbol: _ } => "unsupported-symbol",
+            Self::UnsupportedCombination { offset: _, char_offset: _, symbols: _ } => "unsupported-combination",
+            Self::OptionConflict { first: _, second: _ } => "option-conflict",
+            Self::InvalidArchive { reason: _ } => "invalid-archive",
+            Self::InvalidOptions { reason: _ } => "invalid-options",
+            Self::Render(_) => "render",
+            Self::InvalidFingerprint { field: _ } => "invalid-fingerprint",
+        }
+    }
+}
+
+fn write_codepoints(formatter: &mut fmt::Formatter<'_>, symbols: &str) -> fmt::Result {
+    symbols.chars().enumerate().try_for_each(|(i, symbol)| {
+        write!(formatter, "{}U+{:04X}", if i == 0 { "" } else { " " }, u32::from(symbol))
+    })
+}
+
+impl From<RenderError> for Error {
+    fn from(error: RenderError) -> Self {
+        Self::Render(error)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ParseError { input, reason } => write!(formatter, "invalid ipa {:?}: {}", input, reason),
+            Self::InvalidUtf8 { offset } => write!(formatter, "invalid utf-8 at byte {}", offset),
+            Self::UnknownSymbol { offset, symbol } => write!(formatter, "unknown symbol {:?} at byte {}", symbol, offset),
+            Self::UnsupportedSymbol { offset, char_offset, symbol } => {
+                write!(formatter, "unsupported ipa symbol {:?} (", symbol)?;
+                write_codepoints(formatter, symbol)?;
+                write!(formatter, ") at byte {} (char {})", offset, char_offset)
+            },
+            Self::UnsupportedCombination { offset, char_offset, symbols } => {
+                write!(formatter, "unsupported ipa combination {:?} (", symbols)?;
+                write_codepoints(formatter, symbols)?;
+                write!(formatter, ") at byte {} (char {})", offset, char_offset)
+            },
+            Self::OptionConflict { first, second } => write!(formatter, "options {:?} and {:?} conflict", first, second),
+            Self::InvalidArchive { reason } => write!(formatter, "invalid archive: {}", reason),
+            Self::InvalidOptions { reason } => write!(formatter, "invalid options: {}", reason),
+            Self::Render(error) => write!(formatter, "render failed: {}", error),
+            Self::InvalidFingerprint { field } => write!(formatter, "invalid fingerprint field {:?}", field),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenderError {
+    MissingOverride { position: usize, index: usize },
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingOverride { position, index } => {
+                write!(formatter, "no override {} for phoneme at position {}", index, position)
+            },
+        }
+    }
+}
+
+impl core::error::Error for RenderError {}
+
+#[cfg(test)]
+mod error_tests {
+    use super::*;
+    use alloc::{borrow::ToOwned, string::ToString};
+
+    #[test]
+    fn test_display() {
+        let error = Error::UnsupportedSymbol { offset: 6, char_offset: 4, symbol: "ʘ".to_owned() };
+        assert_eq!(error.to_string(), "unsupported ipa symbol \"ʘ\" (U+0298) at byte 6 (char 4)");
+        let error = Error::UnsupportedCombination { offset: 0, char_offset: 0, symbols: "aʲ".to_owned() };
+        assert_eq!(error.to_string(), "unsupported ipa combination \"aʲ\" (U+0061 U+02B2) at byte 0 (char 0)");
+        let error = Error::OptionConflict { first: "stress-marks", second: "vowel-length" };
+        assert_eq!(error.to_string(), "options \"stress-marks\" and \"vowel-length\" conflict");
+    }
+}
@@ -0,0 +1,35 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExplainRule {
+    Palatalization,
+    Jot,
+    Geminate,
+    Override,
+}
+
+impl ExplainRule {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Palatalization => "palatalization",
+            Self::Jot            => "jot",
+            Self::Geminate       => "geminate",
+            Self::Override       => "override",
+        }
+    }
+}
+
+impl fmt::Display for ExplainRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ExplainStep {
+    pub ipa: Range<usize>,
+    pub ru: Range<usize>,
+    pub rules: Vec<ExplainRule>,
+}
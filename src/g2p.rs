@@ -0,0 +1,105 @@
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+use crate::error::Error;
+use crate::notation::kirshenbaum::KIRSHENBAUM;
+use crate::notation::longest_match;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum G2pPhoneme {
+    Phone(String),
+    Stress,
+    SyllableBoundary,
+    WordBoundary,
+}
+
+impl G2pPhoneme {
+    fn as_ipa(&self) -> &str {
+        match self {
+            Self::Phone(ipa)       => ipa,
+            Self::Stress           => "ˈ",
+            Self::SyllableBoundary => ".",
+            Self::WordBoundary     => " ",
+        }
+    }
+}
+
+pub trait G2pSource {
+    fn phonemes(&self) -> Result<Vec<G2pPhoneme>, Error>;
+}
+
+impl G2pSource for [G2pPhoneme] {
+    fn phonemes(&self) -> Result<Vec<G2pPhoneme>, Error> {
+        Ok(self.to_vec())
+    }
+}
+
+impl G2pSource for Vec<G2pPhoneme> {
+    fn phonemes(&self) -> Result<Vec<G2pPhoneme>, Error> {
+        Ok(self.clone())
+    }
+}
+
+pub(crate) fn to_ipa<S: G2pSource + ?Sized>(source: &S) -> Result<String, Error> {
+    Ok(source.phonemes()?.iter().map(G2pPhoneme::as_ipa).collect())
+}
+
+const ESPEAK: &[(&str, &str)] = &[
+    ("3",   "ɜ"),  ("0",   "ɒ"),  ("a#",  "ɐ"),  ("I#",  "ɨ"),  ("@2",  "ə"),
+    ("_",   " "),  ("_:",  " "),  ("_!",  " "),  ("%",   ""),   ("=",   ""),
+    (",",   ""),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EspeakPhonemes<'a>(pub &'a str);
+
+impl G2pSource for EspeakPhonemes<'_> {
+    fn phonemes(&self) -> Result<Vec<G2pPhoneme>, Error> {
+        let mut phonemes = Vec::new();
+        let mut offset = 0;
+        while let Some(rest) = self.0.get(offset..).filter(|rest| !rest.is_empty()) {
+            let (symbol, ipa) = longest_match(rest, offset, &[ESPEAK, KIRSHENBAUM])?;
+            match (ipa, phonemes.last_mut()) {
+                ("", _) => {},
+                ("ˈ", _) => phonemes.push(G2pPhoneme::Stress),
+                (".", _) => phonemes.push(G2pPhoneme::SyllableBoundary),
+                ("ʲ" | "ː" | "ʰ" | "\u{303}", Some(G2pPhoneme::Phone(phone))) => phone.push_str(ipa),
+                (_, Some(G2pPhoneme::WordBoundary)) if ipa.trim().is_empty() => {},
+                (_, _) if ipa.trim().is_empty() => phonemes.push(G2pPhoneme::WordBoundary),
+                (_, _) => phonemes.push(G2pPhoneme::Phone(ipa.to_owned())),
+            }
+            offset += symbol.len();
+        }
+        Ok(phonemes)
+    }
+}
+
+#[cfg(test)]
+mod g2p_tests {
+    use super::*;
+
+    #[test]
+    fn test_espeak_phonemes() {
+        assert_eq!(EspeakPhonemes("n;'a:  m,a_").phonemes(), Ok(vec![
+            G2pPhoneme::Phone("nʲ".to_owned()),
+            G2pPhoneme::Stress,
+            G2pPhoneme::Phone("aː".to_owned()),
+            G2pPhoneme::WordBoundary,
+            G2pPhoneme::Phone("m".to_owned()),
+            G2pPhoneme::Phone("a".to_owned()),
+            G2pPhoneme::WordBoundary,
+        ]));
+    }
+
+    #[test]
+    fn test_to_ipa() {
+        let phonemes = vec![
+            G2pPhoneme::Phone("p".to_owned()),
+            G2pPhoneme::Stress,
+            G2pPhoneme::Phone("æ".to_owned()),
+            G2pPhoneme::SyllableBoundary,
+            G2pPhoneme::WordBoundary,
+        ];
+        assert_eq!(to_ipa(&phonemes), Ok("pˈæ. ".to_owned()));
+        assert_eq!(to_ipa(&phonemes[..1]), Ok("p".to_owned()));
+    }
+}
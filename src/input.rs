@@ -0,0 +1,320 @@
+use core::iter;
+use alloc::string::String;
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::options::{ReleaseModifiers, RuOptions};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
+pub enum Separator {
+    Space,
+    Tab,
+    LineFeed,
+    CrLf,
+    NoBreakSpace,
+}
+
+impl Separator {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Space        => " ",
+            Self::Tab          => "\t",
+            Self::LineFeed     => "\n",
+            Self::CrLf         => "\r\n",
+            Self::NoBreakSpace => "\u{a0}",
+        }
+    }
+
+    fn strip_prefix(input: &str) -> Option<(Self, &str)> {
+        [Self::CrLf, Self::Space, Self::Tab, Self::LineFeed, Self::NoBreakSpace]
+            .into_iter()
+            .find_map(|separator| input.strip_prefix(separator.as_str()).map(|rest| (separator, rest)))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Release {
+    Schwa,
+    Nasal,
+    Lateral,
+}
+
+impl Release {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Schwa   => "ᵊ",
+            Self::Nasal   => "ⁿ",
+            Self::Lateral => "ˡ",
+        }
+    }
+
+    fn strip_prefix(input: &str) -> Option<(Self, &str)> {
+        [Self::Schwa, Self::Nasal, Self::Lateral]
+            .into_iter()
+            .find_map(|release| input.strip_prefix(release.as_str()).map(|rest| (release, rest)))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Stress {
+    Primary,
+    Secondary,
+}
+
+impl Stress {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Primary   => "ˈ",
+            Self::Secondary => "ˌ",
+        }
+    }
+
+    fn strip_prefix(input: &str) -> Option<(Self, &str)> {
+        [Self::Primary, Self::Secondary]
+            .into_iter()
+            .find_map(|stress| input.strip_prefix(stress.as_str()).map(|rest| (stress, rest)))
+    }
+}
+
+const MORPHEME_BOUNDARY: &str = "+";
+const SYLLABLE_BOUNDARY: &str = ".";
+const CAPITAL: &str = "^";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Token<'a> {
+    Ipa(&'a str),
+    Separator(Separator),
+    Release(Release),
+    Stress(Stress),
+    MorphemeBoundary,
+    SyllableBoundary,
+    Capital,
+    Override { ipa: &'a str, index: usize },
+}
+
+impl<'a> Token<'a> {
+    pub(crate) fn as_str(&self) -> &'a str {
+        match *self {
+            Token::Ipa(ipa_str) => ipa_str,
+            Token::Separator(separator) => separator.as_str(),
+            Token::Release(release) => release.as_str(),
+            Token::Stress(stress) => stress.as_str(),
+            Token::MorphemeBoundary => MORPHEME_BOUNDARY,
+            Token::SyllableBoundary => SYLLABLE_BOUNDARY,
+            Token::Capital => CAPITAL,
+            Token::Override { ipa, index: _ } => ipa,
+        }
+    }
+}
+
+pub(crate) struct Tokens<'a> {
+    rest: &'a str,
+    options: &'a RuOptions,
+}
+
+pub(crate) fn tokenize<'a>(input: &'a str, options: &'a RuOptions) -> Tokens<'a> {
+    Tokens { rest: input, options }
+}
+
+impl<'a> Tokens<'a> {
+    fn strip_token(&self, input: &'a str) -> Option<(Token<'a>, &'a str)> {
+        let overridden = self.options.overrides
+            .iter()
+            .enumerate()
+            .filter(|(_, (ipa, _))| !ipa.is_empty() && input.starts_with(ipa.as_str()))
+            .max_by_key(|(_, (ipa, _))| ipa.len());
+        if let Some((index, (ipa, _))) = overridden {
+            let (ipa, rest) = input.split_at(ipa.len());
+            return Some((Token::Override { ipa, index }, rest));
+        }
+        if let Some((separator, rest)) = Separator::strip_prefix(input) {
+            return Some((Token::Separator(separator), rest));
+        }
+        if let Some(rest) = input.strip_prefix(MORPHEME_BOUNDARY) {
+            return Some((Token::MorphemeBoundary, rest));
+        }
+        if let Some(rest) = input.strip_prefix(SYLLABLE_BOUNDARY) {
+            return Some((Token::SyllableBoundary, rest));
+        }
+        if let Some(rest) = input.strip_prefix(CAPITAL) {
+            return Some((Token::Capital, rest));
+        }
+        if let Some((stress, rest)) = Stress::strip_prefix(input) {
+            return Some((Token::Stress(stress), rest));
+        }
+        match self.options.release_modifiers {
+            ReleaseModifiers::Reject => None,
+            ReleaseModifiers::Drop | ReleaseModifiers::ReducedVowel => Release::strip_prefix(input)
+                .map(|(release, rest)| (Token::Release(release), rest)),
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        if let Some((token, rest)) = self.strip_token(self.rest) {
+            self.rest = rest;
+            return Some(token);
+        }
+        let end = self.rest
+            .char_indices()
+            .map(|(i, _)| i)
+            .find(|&i| i > 0 && self.strip_token(&self.rest[i..]).is_some())
+            .unwrap_or(self.rest.len());
+        let (word, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(Token::Ipa(word))
+    }
+}
+
+#[inline]
+pub(crate) fn lookalikes_lookup(symbol: char) -> char {
+    match symbol {
+        'ʹ' | '′' | '\u{321}' => 'ʲ',
+        ':' | '꞉' | '˸'       => 'ː',
+        'а'                   => 'a',
+        'е'                   => 'e',
+        'о'                   => 'o',
+        'р'                   => 'p',
+        'с'                   => 'c',
+        'у'                   => 'y',
+        'х'                   => 'x',
+        'і'                   => 'i',
+        'ј'                   => 'j',
+        '‿'                   => '+',
+        'α'                   => 'ɑ',
+        'ε'                   => 'ɛ',
+        'γ'                   => 'ɣ',
+        'φ'                   => 'ɸ',
+        _                     => symbol,
+    }
+}
+
+#[inline]
+pub(crate) fn is_ignored_diacritic(symbol: char) -> bool {
+    matches!(symbol,
+        '\u{32a}' // dental
+        | '\u{31a}' // no audible release
+    )
+}
+
+#[inline]
+fn is_modifier(symbol: char) -> bool {
+    matches!(symbol, '\u{2b0}'..='\u{2ff}' | '\u{300}'..='\u{36f}' | ':' | '′' | '꞉') && !matches!(symbol, 'ˈ' | 'ˌ')
+}
+
+pub(crate) fn graphemes(input: &str) -> impl Iterator<Item = (usize, &str)> + '_ {
+    let mut symbols = input.char_indices().peekable();
+    iter::from_fn(move || {
+        let (start, _) = symbols.next()?;
+        let mut is_tied = false;
+        while let Some(&(_, symbol)) = symbols.peek().filter(|&&(_, symbol)| is_tied || is_modifier(symbol)) {
+            is_tied = matches!(symbol, '\u{361}' | '\u{35c}');
+            symbols.next();
+        }
+        let end = symbols.peek().map_or(input.len(), |&(end, _)| end);
+        Some((start, &input[start..end]))
+    })
+}
+
+pub(crate) fn normalize(input: &str) -> String {
+    input.nfc()
+        .filter(|&symbol| !is_ignored_diacritic(symbol))
+        .map(lookalikes_lookup)
+        .collect()
+}
+
+#[cfg(test)]
+mod input_tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(tokenize("nʲæ\r\nnʲæn\u{a0}\tæ", &RuOptions::default()).collect::<Vec<_>>(), vec![
+            Token::Ipa("nʲæ"),
+            Token::Separator(Separator::CrLf),
+            Token::Ipa("nʲæn"),
+            Token::Separator(Separator::NoBreakSpace),
+            Token::Separator(Separator::Tab),
+            Token::Ipa("æ"),
+        ]);
+    }
+
+    #[test]
+    fn test_normalize_lookalikes() {
+        assert_eq!(normalize("n\u{2032}\u{430}\u{2d0}"), "nʲaː");
+        assert_eq!(normalize("mʹæ:u"), "mʲæːu");
+    }
+
+    #[test]
+    fn test_normalize_ignored_diacritics() {
+        assert_eq!(normalize("n\u{32a}æp\u{31a}"), "næp");
+    }
+
+    #[test]
+    fn test_tokenize_stress() {
+        assert_eq!(tokenize("ˈnʲæˌnʲæ", &RuOptions::default()).collect::<Vec<_>>(), vec![
+            Token::Stress(Stress::Primary),
+            Token::Ipa("nʲæ"),
+            Token::Stress(Stress::Secondary),
+            Token::Ipa("nʲæ"),
+        ]);
+    }
+
+    #[test]
+    fn test_tokenize_syllable_boundary() {
+        assert_eq!(tokenize("æ.u", &RuOptions::default()).collect::<Vec<_>>(), vec![
+            Token::Ipa("æ"),
+            Token::SyllableBoundary,
+            Token::Ipa("u"),
+        ]);
+    }
+
+    #[test]
+    fn test_tokenize_lone_cr() {
+        assert_eq!(tokenize("æ\ræ", &RuOptions::default()).collect::<Vec<_>>(), vec![Token::Ipa("æ\ræ")]);
+    }
+
+    #[test]
+    fn test_tokenize_overrides() {
+        let options = RuOptions {
+            overrides: vec![("θ".to_owned(), "т".to_owned()), ("θʲ".to_owned(), "ть".to_owned())],
+            ..RuOptions::default()
+        };
+        assert_eq!(tokenize("æθʲθ", &options).collect::<Vec<_>>(), vec![
+            Token::Ipa("æ"),
+            Token::Override { ipa: "θʲ", index: 1 },
+            Token::Override { ipa: "θ", index: 0 },
+        ]);
+    }
+
+    #[test]
+    fn test_graphemes() {
+        assert_eq!(graphemes("t͡sʲa ˈnʲ:").collect::<Vec<_>>(), vec![
+            (0, "t͡sʲ"),
+            (6, "a"),
+            (7, " "),
+            (8, "ˈ"),
+            (10, "nʲ:"),
+        ]);
+    }
+
+    #[test]
+    fn test_tokenize_release() {
+        assert_eq!(tokenize("tᵊnˡ", &RuOptions::default()).collect::<Vec<_>>(), vec![Token::Ipa("tᵊnˡ")]);
+        let options = RuOptions { release_modifiers: ReleaseModifiers::Drop, ..RuOptions::default() };
+        assert_eq!(tokenize("tᵊnˡ", &options).collect::<Vec<_>>(), vec![
+            Token::Ipa("t"),
+            Token::Release(Release::Schwa),
+            Token::Ipa("n"),
+            Token::Release(Release::Lateral),
+        ]);
+    }
+}
@@ -0,0 +1,76 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+#[cfg(feature = "rkyv")]
+pub mod archive;
+mod audit;
+mod builder;
+mod converter;
+pub mod corpus;
+#[cfg(feature = "dictionary")]
+mod dictionary;
+mod error;
+mod explain;
+mod g2p;
+mod input;
+pub mod metrics;
+mod notation;
+mod options;
+#[cfg(feature = "std")]
+mod pipeline;
+#[cfg(feature = "std")]
+mod pool;
+pub mod prelude;
+mod preset;
+mod render;
+mod report;
+mod ru;
+mod rule;
+#[cfg(feature = "icu")]
+mod segment;
+#[cfg(feature = "serde")]
+pub mod serialize;
+mod stream;
+mod symbols;
+#[cfg(feature = "testkit")]
+pub mod testkit;
+#[cfg(all(feature = "std", not(feature = "testkit")))]
+mod testkit;
+mod words;
+
+pub use audit::{AuditEvent, AuditRule, AuditSink};
+pub use builder::RuBuilder;
+pub use converter::{convert, Converter};
+#[cfg(feature = "dictionary")]
+pub use dictionary::Dictionary;
+pub use error::{Error, RenderError};
+pub use explain::{ExplainRule, ExplainStep};
+pub use g2p::{EspeakPhonemes, G2pPhoneme, G2pSource};
+pub use input::Separator;
+pub use notation::{ArpabetStr, CxsStr, IpaStr, KirshenbaumStr, TipaStr, XsampaStr};
+pub use options::{
+    Affricates, ClusterRules, ClusterStrategy, FinalDevoicing, Geminates, Hiatus, HushingSoftSign,
+    Hyphenation, InitialE, JotAfterConsonant, JotRules, JotSpelling, NormalizationForm, Orthography,
+    Preset, QRules, QSpelling, ReleaseModifiers, RuOptions, StressMarks, TseVowel, VowelLength,
+    VowelReduction, YoPolicy,
+};
+#[cfg(feature = "std")]
+pub use pipeline::{BatchOutput, BatchPipeline};
+#[cfg(feature = "std")]
+pub use pool::ConverterPool;
+pub use render::{PlainText, Render};
+pub use report::{Approximation, ConversionReport, ConversionWarning};
+pub use ru::{Consonants, PalatalizedOnlyConsonants, Phoneme, PhonemeSeq, Ru, Vowels};
+#[cfg(feature = "rkyv")]
+pub use ru::{ArchivedPhoneme, ArchivedPhonemeSeq};
+pub use rule::{Jers, RuRule};
+#[cfg(feature = "icu")]
+pub use segment::IcuSegmenter;
+pub use stream::RuStream;
+pub use symbols::{supported_symbols, supported_symbols_with, SupportedSymbol, SymbolKind};
+#[cfg(feature = "std")]
+pub use testkit::{self_test, SelfTestReport};
+pub use words::{RuWord, RuWords};
@@ -1,15 +1,709 @@
-mod ru;
+mod messages;
 
-use clap::Parser;
-use ru::Ru;
+use std::collections::HashSet;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::{Component, Path, PathBuf};
+use std::{env, fs, process};
+
+use clap::builder::PossibleValuesParser;
+use clap::{value_parser, Arg, ArgAction, ArgMatches, CommandFactory, FromArgMatches, Parser, ValueEnum};
+use ipa2ru::{Preset, Ru, RuOptions};
+use rayon::prelude::*;
+
+use messages::{Lang, Message};
+
+const SELF_TEST: &str = "self-test";
+const CONVERT: &str = "convert";
+const CSV: &str = "csv";
+const REPL: &str = "repl";
+const PROMPT: &str = "ipa2ru> ";
+const COLORS: [u8; 6] = [31, 32, 33, 34, 35, 36];
+const BARE_FLAGS: [(&str, &str); 1] = [("stress_marks", "acute")];
+const SYMBOL_FLAGS: [(&str, &str); 3] = [("w", "w"), ("theta", "θ"), ("eth", "ð")];
+const LATIN: [(char, char); 23] = [
+    ('a', 'а'), ('b', 'б'), ('v', 'в'), ('g', 'г'), ('d', 'д'), ('e', 'е'), ('z', 'з'), ('i', 'и'), ('j', 'й'), ('k', 'к'),
+    ('l', 'л'), ('m', 'м'), ('n', 'н'), ('o', 'о'), ('p', 'п'), ('r', 'р'), ('s', 'с'), ('t', 'т'), ('u', 'у'), ('f', 'ф'),
+    ('h', 'х'), ('c', 'ц'), ('y', 'ы'),
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Jsonl,
+    #[clap(skip)]
+    Explain,
+}
+
+#[derive(serde::Deserialize)]
+struct Entry {
+    ipa: String,
+}
 
 #[derive(Parser)]
 #[clap(author, version, about, long_about = None)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Cli {
     #[clap(value_parser)]
-    ipa: String,
+    ipa: Option<String>,
+    #[clap(long, value_parser = Lang::CODES)]
+    lang: Option<String>,
+    #[clap(long, value_parser, value_name = "FILE")]
+    report: Option<PathBuf>,
+    #[clap(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    #[clap(long, conflicts_with = "format")]
+    explain: bool,
+}
+
+fn command(lang: Lang) -> clap::Command<'static> {
+    Cli::command()
+        .about(Message::About.text(lang))
+        .mut_arg("ipa", |arg| arg.help(Message::IpaHelp.text(lang)))
+        .mut_arg("lang", |arg| arg.help(Message::LangHelp.text(lang)))
+        .mut_arg("report", |arg| arg.help(Message::ReportHelp.text(lang)))
+        .mut_arg("format", |arg| arg.help(Message::FormatHelp.text(lang)))
+        .mut_arg("explain", |arg| arg.help(Message::ExplainHelp.text(lang)))
+        .mut_arg("help", |arg| arg.help(Message::HelpHelp.text(lang)))
+        .mut_arg("version", |arg| arg.help(Message::VersionHelp.text(lang)))
+        .args(option_args(lang))
+        .subcommand(clap::Command::new(SELF_TEST).about(Message::SelfTestAbout.text(lang)))
+        .subcommand(clap::Command::new(REPL).about(Message::ReplAbout.text(lang)))
+        .subcommand(
+            clap::Command::new(CONVERT)
+                .about(Message::ConvertAbout.text(lang))
+                .arg(Arg::new("files")
+                    .value_parser(value_parser!(PathBuf))
+                    .value_name("FILE")
+                    .multiple_values(true)
+                    .required(true)
+                    .help(Message::FilesHelp.text(lang)))
+                .arg(Arg::new("output")
+                    .short('o')
+                    .long("output")
+                    .value_parser(value_parser!(PathBuf))
+                    .value_name("DIR")
+                    .required(true)
+                    .help(Message::OutputHelp.text(lang)))
+                .arg(Arg::new("report")
+                    .long("report")
+                    .value_parser(value_parser!(PathBuf))
+                    .value_name("FILE")
+                    .help(Message::ReportHelp.text(lang))),
+        )
+        .subcommand(
+            clap::Command::new(CSV)
+                .about(Message::CsvAbout.text(lang))
+                .arg(Arg::new("input")
+                    .value_parser(value_parser!(PathBuf))
+                    .value_name("FILE")
+                    .required(true)
+                    .help(Message::CsvInputHelp.text(lang)))
+                .arg(Arg::new("column")
+                    .long("column")
+                    .value_parser(value_parser!(String))
+                    .value_name("NAME")
+                    .default_value("ipa")
+                    .help(Message::ColumnHelp.text(lang)))
+                .arg(Arg::new("output-column")
+                    .long("output-column")
+                    .value_parser(value_parser!(String))
+                    .value_name("NAME")
+                    .default_value("ru")
+                    .help(Message::NewColumnHelp.text(lang))),
+        )
+}
+
+fn option_args(lang: Lang) -> Vec<Arg<'static>> {
+    let flags = RuOptions::keys().into_iter().map(|(key, names)| {
+        let flag = Arg::new(key)
+            .long(Box::leak(key.replace('_', "-").into_boxed_str()))
+            .takes_value(true)
+            .global(true)
+            .help(Message::OptionHelp.text(lang));
+        let flag = match names {
+            [] => flag.value_parser(value_parser!(String)).value_name("N"),
+            _  => flag.value_parser(PossibleValuesParser::new(names.iter().copied())).value_name("VALUE"),
+        };
+        match BARE_FLAGS.iter().find(|(bare, _)| *bare == key) {
+            Some((_, value)) => flag.min_values(0).require_equals(true).default_missing_value(*value),
+            None             => flag,
+        }
+    });
+    let symbols = SYMBOL_FLAGS.iter().map(|(flag, _)| {
+        Arg::new(*flag)
+            .long(*flag)
+            .takes_value(true)
+            .value_parser(value_parser!(String))
+            .value_name("LETTERS")
+            .global(true)
+            .help(Message::SymbolHelp.text(lang))
+    });
+    [
+        Arg::new("config")
+            .long("config")
+            .takes_value(true)
+            .value_parser(value_parser!(PathBuf))
+            .value_name("FILE")
+            .global(true)
+            .help(Message::ConfigHelp.text(lang)),
+        Arg::new("preset")
+            .long("preset")
+            .takes_value(true)
+            .value_parser(value_parser!(Preset))
+            .value_name("NAME")
+            .global(true)
+            .conflicts_with("config")
+            .help(Message::PresetHelp.text(lang)),
+        Arg::new("override")
+            .long("override")
+            .takes_value(true)
+            .action(ArgAction::Append)
+            .value_parser(value_parser!(String))
+            .value_name("IPA>RU")
+            .global(true)
+            .help(Message::OverrideHelp.text(lang)),
+        Arg::new("yo")
+            .long("yo")
+            .takes_value(true)
+            .value_parser(PossibleValuesParser::new(["true", "false"]))
+            .value_name("BOOL")
+            .min_values(0)
+            .require_equals(true)
+            .default_missing_value("true")
+            .global(true)
+            .help(Message::YoHelp.text(lang)),
+    ].into_iter().chain(flags).chain(symbols).collect()
+}
+
+fn self_test(lang: Lang) {
+    let report = ipa2ru::self_test();
+    report.failures.iter()
+        .for_each(|failure| eprintln!("{} {}", Message::SelfTestFailure.text(lang), failure));
+    println!(
+        "{}: {}, {}: {}",
+        Message::SelfTestChecks.text(lang), report.checks,
+        Message::SelfTestFailed.text(lang), report.failures.len()
+    );
+    if !report.is_ok() {
+        process::exit(1);
+    }
+}
+
+fn report(path: &Path, corpus: &str, options: &RuOptions, lang: Lang) {
+    if let Err(error) = fs::write(path, ipa2ru::corpus::html_report(corpus, options)) {
+        eprintln!("{}: {}", Message::ReportFailed.text(lang), error);
+        process::exit(1);
+    }
+}
+
+fn config(path: &Path, lang: Lang) -> RuOptions {
+    let options = fs::read_to_string(path)
+        .map_err(|error| error.to_string())
+        .and_then(|source| match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => RuOptions::from_toml_str(&source).map_err(|error| error.to_string()),
+            _ => RuOptions::from_json_str(&source).map_err(|error| error.to_string()),
+        });
+    options.unwrap_or_else(|error| {
+        eprintln!("{}: {}", Message::ConfigFailed.text(lang), error);
+        process::exit(1);
+    })
+}
+
+fn cyrillic(letters: &str) -> String {
+    letters.chars()
+        .map(|letter| LATIN.iter().find(|(latin, _)| *latin == letter).map_or(letter, |(_, cyrillic)| *cyrillic))
+        .collect()
+}
+
+fn conversion_options(matches: &ArgMatches, lang: Lang) -> RuOptions {
+    let base = match (matches.get_one::<PathBuf>("config"), matches.get_one::<Preset>("preset")) {
+        (Some(path), _)      => config(path, lang),
+        (None, Some(preset)) => RuOptions::preset(*preset),
+        (None, None)         => RuOptions::default(),
+    };
+    let flags = RuOptions::keys().into_iter()
+        .filter_map(|(key, _)| matches.get_one::<String>(key).map(|value| (key, value.to_owned())));
+    let yo = matches.get_one::<String>("yo")
+        .map(|value| ("yo_policy", if value == "true" { "yo" } else { "ye" }.to_owned()));
+    let symbols = SYMBOL_FLAGS.iter().filter_map(|(flag, ipa)| {
+        matches.get_one::<String>(flag).map(|value| ("override", format!("{}>{}", ipa, cyrillic(value))))
+    });
+    let overrides = matches.get_many::<String>("override").into_iter().flatten()
+        .map(|value| ("override", value.to_owned()));
+    let options = flags.chain(yo).chain(symbols).chain(overrides)
+        .try_fold(base, |mut options, (key, value)| options.set(key, &value).map(|()| options));
+    options.unwrap_or_else(|error| {
+        eprintln!("{}: {}", Message::OptionFailed.text(lang), error);
+        process::exit(1);
+    })
+}
+
+fn width(text: &str) -> usize {
+    text.chars().filter(|c| !('\u{300}'..='\u{36f}').contains(c)).count()
+}
+
+fn paint(text: &str, color: Option<u8>) -> String {
+    match color {
+        Some(color) => format!("\x1b[{}m{}\x1b[0m", color, text),
+        None => text.to_owned(),
+    }
+}
+
+fn explanation(ipa: &str, ru: &Ru) -> String {
+    let is_colored = io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none();
+    let mut rows = [String::new(), String::new()];
+    let mut notes = Vec::new();
+    for (k, step) in ru.explain().into_iter().enumerate() {
+        let color = is_colored.then_some(COLORS[k % COLORS.len()]);
+        let source = ipa.get(step.ipa).unwrap_or_default();
+        let target = ru.as_str().get(step.ru).unwrap_or_default();
+        let column = width(source).max(width(target)) + 1;
+        for (row, text) in rows.iter_mut().zip([source, target]) {
+            row.push_str(&paint(text, color));
+            row.push_str(&" ".repeat(column - width(text)));
+        }
+        if !step.rules.is_empty() {
+            let rules = step.rules.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+            let target = if target.is_empty() { "∅" } else { target };
+            notes.push(format!("{} → {}: {}", paint(source, color), paint(target, color), rules));
+        }
+    }
+    rows.iter().map(|row| row.trim_end().to_owned()).chain(notes).collect::<Vec<_>>().join("\n")
+}
+
+fn jsonl(ipa: &str, options: &RuOptions) -> Result<String, ipa2ru::Error> {
+    let (ru, report) = Ru::try_from_ipa_reported(ipa, options.clone())?;
+    let warnings = report.warnings.iter().map(ToString::to_string).collect::<Vec<_>>();
+    Ok(serde_json::json!({ "ipa": ipa, "ru": ru.to_string(), "warnings": warnings }).to_string())
+}
+
+fn jsonl_error(ipa: Option<&str>, error: &str) -> String {
+    serde_json::json!({ "ipa": ipa, "error": error }).to_string()
+}
+
+fn convert_line(line: &str, format: Format, options: &RuOptions) -> Result<String, String> {
+    match format {
+        Format::Text => {
+            Ru::try_from_ipa_with(line, options.clone()).map(|ru| ru.to_string()).map_err(|error| error.to_string())
+        },
+        Format::Jsonl => {
+            let Entry { ipa } = serde_json::from_str(line).map_err(|error| error.to_string())?;
+            jsonl(&ipa, options).map_err(|error| error.to_string())
+        },
+        Format::Explain => {
+            Ru::try_from_ipa_with(line, options.clone()).map(|ru| explanation(line, &ru)).map_err(|error| error.to_string())
+        },
+    }
 }
 
-fn main() -> Result<(), ipa_sounds::Error> {
-    Ru::try_from(Cli::parse().ipa).map(|ru| println!("{}", ru))
+fn convert_lines<R: BufRead>(input: R, format: Format, options: &RuOptions, lang: Lang) -> bool {
+    let mut is_ok = true;
+    for (index, line) in input.lines().enumerate() {
+        let line = match line {
+            Ok(line) => line,
+            Err(error) => {
+                eprintln!("{}: {}", Message::ReadFailed.text(lang), error);
+                return false;
+            },
+        };
+        match convert_line(&line, format, options) {
+            Ok(output) => println!("{}", output),
+            Err(error) => {
+                if format == Format::Jsonl {
+                    let entry = serde_json::from_str::<Entry>(&line).ok();
+                    println!("{}", jsonl_error(entry.as_ref().map(|entry| entry.ipa.as_str()), &error));
+                }
+                eprintln!("{} {}: {}", Message::LineFailed.text(lang), index + 1, error);
+                is_ok = false;
+            },
+        }
+    }
+    is_ok
+}
+
+fn report_corpus(input: &str, format: Format) -> String {
+    input.lines()
+        .map(|line| match format {
+            Format::Jsonl => serde_json::from_str::<Entry>(line).map_or_else(|_| line.to_owned(), |entry| entry.ipa),
+            _             => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn report_files(path: &Path, files: &[PathBuf], options: &RuOptions, lang: Lang) {
+    let corpus = files.iter().filter_map(|file| fs::read_to_string(file).ok()).collect::<Vec<_>>().join("\n");
+    report(path, &corpus, options, lang);
+}
+
+fn output_path(path: &Path, outdir: &Path) -> PathBuf {
+    outdir.join(path.components().filter(|component| matches!(component, Component::Normal(_))).collect::<PathBuf>())
+}
+
+fn convert_file(path: &Path, outdir: &Path, options: &RuOptions, lang: Lang) -> bool {
+    let source = match fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(error) => {
+            eprintln!("{} {}: {}", Message::FileFailed.text(lang), path.display(), error);
+            return false;
+        },
+    };
+    let (text, is_ok) = source.lines().enumerate().fold((String::new(), true), |(mut text, is_ok), (index, line)| {
+        let is_ok = match Ru::try_from_ipa_with(line, options.clone()) {
+            Ok(ru) => {
+                text.push_str(&ru.to_string());
+                is_ok
+            },
+            Err(error) => {
+                eprintln!("{} {}:{}: {}", Message::LineFailed.text(lang), path.display(), index + 1, error);
+                false
+            },
+        };
+        text.push('\n');
+        (text, is_ok)
+    });
+    let output = output_path(path, outdir);
+    let written = output.parent().map_or(Ok(()), fs::create_dir_all).and_then(|()| fs::write(&output, text));
+    if let Err(error) = written {
+        eprintln!("{} {}: {}", Message::FileFailed.text(lang), output.display(), error);
+        return false;
+    }
+    is_ok
+}
+
+fn convert_files(files: &[PathBuf], outdir: &Path, options: &RuOptions, lang: Lang) -> bool {
+    let mut outputs = HashSet::new();
+    let collision = files.iter().map(|path| output_path(path, outdir)).find(|output| !outputs.insert(output.clone()));
+    if let Some(output) = collision {
+        eprintln!("{}: {}", Message::OutputCollision.text(lang), output.display());
+        return false;
+    }
+    files.par_iter()
+        .map(|path| convert_file(path, outdir, options, lang))
+        .reduce(|| true, |first, second| first && second)
+}
+
+fn convert_csv<W: Write>(matches: &ArgMatches, output: W, options: &RuOptions, lang: Lang) -> Result<bool, csv::Error> {
+    let input = matches.get_one::<PathBuf>("input").expect("input is required");
+    let column = matches.get_one::<String>("column").expect("column has a default");
+    let output_column = matches.get_one::<String>("output-column").expect("output column has a default");
+    let mut reader = csv::Reader::from_path(input)?;
+    let mut writer = csv::Writer::from_writer(output);
+    let headers = reader.headers()?.clone();
+    let Some(index) = headers.iter().position(|header| header == column) else {
+        eprintln!("{}: {}", Message::ColumnMissing.text(lang), column);
+        return Ok(false);
+    };
+    writer.write_record(headers.iter().chain([output_column.as_str()]))?;
+    let mut is_ok = true;
+    for record in reader.records() {
+        let record = record?;
+        let ru = Ru::try_from_ipa_with(&record[index], options.clone()).map(|ru| ru.to_string()).unwrap_or_else(|error| {
+            let line = record.position().map_or(0, |position| position.line());
+            eprintln!("{} {}:{}: {}", Message::LineFailed.text(lang), input.display(), line, error);
+            is_ok = false;
+            String::new()
+        });
+        writer.write_record(record.iter().chain([ru.as_str()]))?;
+    }
+    writer.flush()?;
+    Ok(is_ok)
+}
+
+fn repl_command(command: &str, options: &mut RuOptions, initial: &RuOptions, lang: Lang) -> Result<String, String> {
+    let mut words = command.split_whitespace();
+    match (words.next(), words.next(), words.next()) {
+        (Some("set"), Some(key), Some(value)) => {
+            options.set(&key.replace('-', "_"), value).map(|()| String::new()).map_err(|error| error.to_string())
+        },
+        (Some("preset"), Some(name), None) => name.parse::<Preset>()
+            .map(|preset| *options = RuOptions::preset(preset))
+            .map(|()| String::new())
+            .map_err(|error| error.to_string()),
+        (Some("reset"), None, None) => {
+            *options = initial.clone();
+            Ok(String::new())
+        },
+        (Some("show"), None, None) => Ok(format!("{:?}", options)),
+        _ => Err(format!("{} :{}", Message::UnknownCommand.text(lang), command)),
+    }
+}
+
+fn repl<R: BufRead, W: Write>(input: R, mut output: W, initial: RuOptions, lang: Lang) -> io::Result<()> {
+    let mut options = initial.clone();
+    write!(output, "{}", PROMPT)?;
+    output.flush()?;
+    for line in input.lines() {
+        let line = line?;
+        let line = line.trim();
+        match line {
+            ":quit" | ":q" => return Ok(()),
+            _ if line.starts_with(':') => match repl_command(&line[1..], &mut options, &initial, lang) {
+                Ok(reply) if reply.is_empty() => {},
+                Ok(reply) => writeln!(output, "{}", reply)?,
+                Err(error) => writeln!(output, "{}", error)?,
+            },
+            "" => {},
+            _ => match Ru::try_from_ipa_with(line, options.clone()) {
+                Ok(ru) => writeln!(output, "{}", ru)?,
+                Err(error) => writeln!(output, "{}", error)?,
+            },
+        }
+        write!(output, "{}", PROMPT)?;
+        output.flush()?;
+    }
+    writeln!(output)
+}
+
+fn main() -> Result<(), ipa2ru::Error> {
+    let lang = Lang::detect(env::args());
+    let matches = command(lang).get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|error| error.exit());
+    let lang = cli.lang.as_deref().and_then(Lang::from_code).unwrap_or(lang);
+    match matches.subcommand() {
+        Some((SELF_TEST, _)) => {
+            self_test(lang);
+            Ok(())
+        },
+        Some((REPL, matches)) => {
+            let options = conversion_options(matches, lang);
+            if let Err(error) = repl(io::stdin().lock(), io::stdout().lock(), options, lang) {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+            Ok(())
+        },
+        Some((CONVERT, matches)) => {
+            let options = conversion_options(matches, lang);
+            let files = matches.get_many::<PathBuf>("files").into_iter().flatten().cloned().collect::<Vec<_>>();
+            let outdir = matches.get_one::<PathBuf>("output").expect("output is required");
+            if let Some(path) = matches.get_one::<PathBuf>("report") {
+                report_files(path, &files, &options, lang);
+            }
+            if !convert_files(&files, outdir, &options, lang) {
+                process::exit(1);
+            }
+            Ok(())
+        },
+        Some((CSV, matches)) => {
+            let options = conversion_options(matches, lang);
+            let converted = convert_csv(matches, io::stdout().lock(), &options, lang).unwrap_or_else(|error| {
+                eprintln!("{}: {}", Message::CsvFailed.text(lang), error);
+                false
+            });
+            if !converted {
+                process::exit(1);
+            }
+            Ok(())
+        },
+        _ => {
+            let options = conversion_options(&matches, lang);
+            let format = if cli.explain { Format::Explain } else { cli.format };
+            let Some(ipa) = cli.ipa else {
+                let is_ok = match cli.report {
+                    Some(path) => {
+                        let input = io::read_to_string(io::stdin()).unwrap_or_else(|error| {
+                            eprintln!("{}: {}", Message::ReadFailed.text(lang), error);
+                            process::exit(1);
+                        });
+                        report(&path, &report_corpus(&input, format), &options, lang);
+                        convert_lines(input.as_bytes(), format, &options, lang)
+                    },
+                    None => convert_lines(io::stdin().lock(), format, &options, lang),
+                };
+                if !is_ok {
+                    process::exit(1);
+                }
+                return Ok(());
+            };
+            if let Some(path) = cli.report {
+                report(&path, &ipa, &options, lang);
+            }
+            match format {
+                Format::Explain => Ru::try_from_ipa_with(&ipa, options).map(|ru| println!("{}", explanation(&ipa, &ru))),
+                Format::Jsonl => jsonl(&ipa, &options)
+                    .map(|output| println!("{}", output))
+                    .inspect_err(|error| println!("{}", jsonl_error(Some(ipa.as_str()), &error.to_string()))),
+                _ => Ru::try_from_ipa_with(&ipa, options).map(|ru| println!("{}", ru)),
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod main_tests {
+    use super::*;
+
+    struct Broken;
+
+    impl io::Read for Broken {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("broken"))
+        }
+    }
+
+    fn tempdir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("ipa2ru-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_convert_lines() {
+        let options = RuOptions::default();
+        assert!(convert_lines("nʲæ\n\nmʲæː\n".as_bytes(), Format::Text, &options, Lang::En));
+        assert!(!convert_lines("nʲæ\nnʲæ?\n".as_bytes(), Format::Text, &options, Lang::En));
+        assert!(!convert_lines(io::BufReader::new(Broken), Format::Text, &options, Lang::En));
+    }
+
+    #[test]
+    fn test_option_flags() {
+        let options = |args: &[&str]| {
+            let matches = command(Lang::En).try_get_matches_from(["ipa2ru"].into_iter().chain(args.iter().copied())).unwrap();
+            conversion_options(&matches, Lang::En)
+        };
+        let mut expected = RuOptions::preset(Preset::English);
+        [("yo_policy", "ye"), ("stress_marks", "acute"), ("geminates", "single"), ("override", "w>в"), ("override", "θ>т")]
+            .iter()
+            .for_each(|(key, value)| expected.set(key, value).unwrap());
+        let args = ["--yo=false", "--w=v", "--theta=t", "--stress-marks", "--geminates", "single", "--preset", "english"];
+        assert_eq!(options(&args), expected);
+        assert_eq!(options(&["--stress-marks=omit", "--yo", "nʲæ"]), RuOptions::default());
+        assert!(command(Lang::En).try_get_matches_from(["ipa2ru", "--geminates", "triple"]).is_err());
+        assert!(command(Lang::En).try_get_matches_from(["ipa2ru", "--yo=ye"]).is_err());
+    }
+
+    #[test]
+    fn test_cyrillic() {
+        assert_eq!(cyrillic("ts"), "тс");
+        assert_eq!(cyrillic("в"), "в");
+    }
+
+    #[test]
+    fn test_convert_line() {
+        let options = RuOptions::default();
+        assert_eq!(convert_line("nʲæ", Format::Text, &options), Ok("ня".to_owned()));
+        assert!(convert_line("nʲæ?", Format::Text, &options).is_err());
+        let output = convert_line(r#"{"ipa": "nʲæ"}"#, Format::Jsonl, &options);
+        assert_eq!(output, Ok(r#"{"ipa":"nʲæ","ru":"ня","warnings":[]}"#.to_owned()));
+        assert!(convert_line(r#"{"ipa": "nʲæ?"}"#, Format::Jsonl, &options).is_err());
+        assert!(convert_line("nʲæ", Format::Jsonl, &options).is_err());
+        assert_eq!(jsonl("nʲæ", &options).unwrap(), output.unwrap());
+        assert_eq!(jsonl_error(Some("nʲæ?"), "invalid"), r#"{"error":"invalid","ipa":"nʲæ?"}"#);
+        assert_eq!(jsonl_error(None, "invalid"), r#"{"error":"invalid","ipa":null}"#);
+    }
+
+    #[test]
+    fn test_output_path() {
+        let outdir = Path::new("out");
+        assert_eq!(output_path(Path::new("a/b.txt"), outdir), Path::new("out/a/b.txt"));
+        assert_eq!(output_path(Path::new("./a/b.txt"), outdir), Path::new("out/a/b.txt"));
+        assert_eq!(output_path(Path::new("../a/b.txt"), outdir), Path::new("out/a/b.txt"));
+        assert_eq!(output_path(Path::new("/a/b.txt"), outdir), Path::new("out/a/b.txt"));
+    }
+
+    #[test]
+    fn test_convert_file() {
+        let dir = tempdir("convert-file");
+        let options = RuOptions::default();
+        let input = dir.join("in").join("words.txt");
+        let outdir = dir.join("out");
+        fs::create_dir_all(dir.join("in")).unwrap();
+        fs::write(&input, "nʲæ\nmʲæ\n").unwrap();
+        assert!(convert_file(&input, &outdir, &options, Lang::En));
+        assert_eq!(fs::read_to_string(output_path(&input, &outdir)).unwrap(), "ня\nмя\n");
+        fs::write(&input, "nʲæ\nnʲæ?\n").unwrap();
+        assert!(!convert_file(&input, &outdir, &options, Lang::En));
+        assert_eq!(fs::read_to_string(output_path(&input, &outdir)).unwrap(), "ня\n\n");
+        assert!(!convert_file(&dir.join("missing.txt"), &outdir, &options, Lang::En));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_convert_files() {
+        let dir = tempdir("convert-files");
+        let options = RuOptions::default();
+        let outdir = dir.join("out");
+        let files = [dir.join("a.txt"), dir.join("b").join("a.txt")];
+        fs::create_dir_all(dir.join("b")).unwrap();
+        files.iter().for_each(|path| fs::write(path, "nʲæ\n").unwrap());
+        assert!(convert_files(&files, &outdir, &options, Lang::En));
+        assert!(files.iter().all(|path| output_path(path, &outdir).is_file()));
+        fs::remove_dir_all(&outdir).unwrap();
+        let files = [dir.join("b").join("a.txt"), dir.join("b").join("..").join("a.txt")];
+        assert!(!convert_files(&files, &outdir, &options, Lang::En));
+        assert!(!outdir.exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_report() {
+        assert_eq!(report_corpus("nʲæ\nmʲæ", Format::Text), "nʲæ\nmʲæ");
+        assert_eq!(report_corpus("{\"ipa\": \"nʲæ\"}\nnʲæ?", Format::Jsonl), "nʲæ\nnʲæ?");
+        let dir = tempdir("report");
+        let files = [dir.join("a.txt"), dir.join("b.txt")];
+        fs::write(&files[0], "nʲæ\n").unwrap();
+        fs::write(&files[1], "nʲʊ\n").unwrap();
+        report_files(&dir.join("report.html"), &files, &RuOptions::default(), Lang::En);
+        let html = fs::read_to_string(dir.join("report.html")).unwrap();
+        assert!(html.contains("<tr class=\"ok\"><td></td><td>nʲæ</td><td>ня</td><td>1.00</td>"));
+        assert!(html.contains("<tr class=\"review\"><td></td><td>nʲʊ</td><td>ню</td><td>0.50</td>"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_convert_csv() {
+        let dir = tempdir("convert-csv");
+        let options = RuOptions::default();
+        let input = dir.join("words.csv");
+        fs::write(&input, "word,ipa\nня,nʲæ\n\"мя, мя\",mʲæ\nbad,nʲæ?\n").unwrap();
+        let convert = |args: &[&str]| {
+            let args = ["ipa2ru", CSV].into_iter().chain(args.iter().copied());
+            let matches = command(Lang::En).try_get_matches_from(args).unwrap();
+            let mut output = Vec::new();
+            let is_ok = convert_csv(matches.subcommand_matches(CSV).unwrap(), &mut output, &options, Lang::En).unwrap();
+            (is_ok, String::from_utf8(output).unwrap())
+        };
+        let input = input.to_str().unwrap();
+        assert_eq!(convert(&[input]), (false, "word,ipa,ru\nня,nʲæ,ня\n\"мя, мя\",mʲæ,мя\nbad,nʲæ?,\n".to_owned()));
+        assert_eq!(convert(&["--output-column", "cyr", input]).1.lines().next(), Some("word,ipa,cyr"));
+        assert_eq!(convert(&["--column", "missing", input]), (false, "".to_owned()));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_repl_command() {
+        let mut initial = RuOptions::default();
+        initial.set("geminates", "single").unwrap();
+        let mut options = initial.clone();
+        assert_eq!(repl_command("set geminates double", &mut options, &initial, Lang::En), Ok(String::new()));
+        assert_eq!(options, RuOptions::default());
+        assert_eq!(repl_command("reset", &mut options, &initial, Lang::En), Ok(String::new()));
+        assert_eq!(options, initial);
+        assert_eq!(repl_command("preset english", &mut options, &initial, Lang::En), Ok(String::new()));
+        assert_eq!(options, RuOptions::preset(Preset::English));
+        assert_eq!(repl_command("show", &mut options, &initial, Lang::En), Ok(format!("{:?}", options)));
+        assert!(repl_command("set gemination single", &mut options, &initial, Lang::En).is_err());
+        assert!(repl_command("preset klingon", &mut options, &initial, Lang::En).is_err());
+        assert_eq!(repl_command("bogus", &mut options, &initial, Lang::En), Err("unknown command :bogus".to_owned()));
+    }
+
+    #[test]
+    fn test_repl() {
+        let mut initial = RuOptions::default();
+        initial.set("geminates", "single").unwrap();
+        let input = "nʲæ\n\n:set geminates double\n:reset\n:show\n:bogus\nnʲæ?\n:q\nmʲæ\n";
+        let mut output = Vec::new();
+        repl(input.as_bytes(), &mut output, initial.clone(), Lang::En).unwrap();
+        let error = Ru::try_from_ipa("nʲæ?").err().unwrap();
+        let expected = [
+            PROMPT, "ня\n", PROMPT, PROMPT, PROMPT, PROMPT, &format!("{:?}\n", initial), PROMPT,
+            "unknown command :bogus\n", PROMPT, &format!("{}\n", error), PROMPT,
+        ];
+        assert_eq!(String::from_utf8(output).unwrap(), expected.concat());
+        let mut output = Vec::new();
+        repl("nʲæ".as_bytes(), &mut output, initial, Lang::En).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), format!("{}ня\n{}\n", PROMPT, PROMPT));
+    }
 }
@@ -0,0 +1,183 @@
+use std::env;
+
+pub const LANG_VAR: &str = "IPA2RU_LANG";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Lang {
+    #[default]
+    En,
+    Ru,
+}
+
+impl Lang {
+    pub const CODES: [&'static str; 2] = ["en", "ru"];
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.get(..2).map(|prefix| prefix.to_ascii_lowercase()).as_deref() {
+            Some("en") => Some(Self::En),
+            Some("ru") => Some(Self::Ru),
+            _ => None,
+        }
+    }
+
+    pub fn detect<I: IntoIterator<Item = String>>(args: I) -> Self {
+        lang_flag(args.into_iter())
+            .or_else(|| env::var(LANG_VAR).ok())
+            .or_else(|| env::var("LANG").ok())
+            .and_then(|code| Self::from_code(&code))
+            .unwrap_or_default()
+    }
+}
+
+fn lang_flag<I: Iterator<Item = String>>(mut args: I) -> Option<String> {
+    while let Some(arg) = args.next() {
+        if arg == "--lang" {
+            return args.next();
+        }
+        if let Some(code) = arg.strip_prefix("--lang=") {
+            return Some(code.to_owned());
+        }
+    }
+    None
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Message {
+    About,
+    IpaHelp,
+    LangHelp,
+    ReportHelp,
+    ConfigHelp,
+    FormatHelp,
+    ExplainHelp,
+    PresetHelp,
+    OverrideHelp,
+    OptionHelp,
+    YoHelp,
+    SymbolHelp,
+    HelpHelp,
+    VersionHelp,
+    SelfTestAbout,
+    SelfTestFailure,
+    SelfTestChecks,
+    SelfTestFailed,
+    ReportFailed,
+    ConfigFailed,
+    ReadFailed,
+    LineFailed,
+    ConvertAbout,
+    FilesHelp,
+    OutputHelp,
+    FileFailed,
+    OutputCollision,
+    CsvAbout,
+    CsvInputHelp,
+    ColumnHelp,
+    NewColumnHelp,
+    ColumnMissing,
+    CsvFailed,
+    OptionFailed,
+    ReplAbout,
+    UnknownCommand,
+}
+
+impl Message {
+    pub fn text(self, lang: Lang) -> &'static str {
+        match (self, lang) {
+            (Self::About,           Lang::En) => "Translator from IPA to russian",
+            (Self::About,           Lang::Ru) => "Транскрипция МФА кириллицей",
+            (Self::IpaHelp,         Lang::En) => "IPA transcription to convert (lines from stdin if omitted)",
+            (Self::IpaHelp,         Lang::Ru) => "Транскрипция МФА для преобразования (без неё — строки из stdin)",
+            (Self::LangHelp,        Lang::En) => "Language of messages (also IPA2RU_LANG or LANG)",
+            (Self::LangHelp,        Lang::Ru) => "Язык сообщений (также IPA2RU_LANG или LANG)",
+            (Self::ReportHelp,      Lang::En) => "Write an HTML report of the conversion to FILE",
+            (Self::ReportHelp,      Lang::Ru) => "Записать HTML-отчёт о преобразовании в FILE",
+            (Self::ConfigHelp,      Lang::En) => "Read conversion options from a TOML or JSON FILE",
+            (Self::ConfigHelp,      Lang::Ru) => "Прочитать настройки преобразования из файла TOML или JSON",
+            (Self::FormatHelp,      Lang::En) => "Line format: text, or jsonl objects with an \"ipa\" field",
+            (Self::FormatHelp,      Lang::Ru) => "Формат строк: text или объекты jsonl с полем \"ipa\"",
+            (Self::ExplainHelp,     Lang::En) => "Show which IPA symbols produced which letters and which rules fired",
+            (Self::ExplainHelp,     Lang::Ru) => "Показать, какие символы МФА дали какие буквы и какие правила сработали",
+            (Self::PresetHelp,      Lang::En) => "Start from a source-language preset (english, german, mandarin, ...)",
+            (Self::PresetHelp,      Lang::Ru) => "Взять за основу набор настроек для языка (english, german, mandarin, ...)",
+            (Self::OverrideHelp,    Lang::En) => "Render an IPA sequence as given Cyrillic, e.g. θ>т (repeatable)",
+            (Self::OverrideHelp,    Lang::Ru) => "Заменить сочетание МФА кириллицей, например θ>т (можно повторять)",
+            (Self::OptionHelp,      Lang::En) => "Set this conversion option",
+            (Self::OptionHelp,      Lang::Ru) => "Задать эту настройку преобразования",
+            (Self::YoHelp,          Lang::En) => "Spell ё; --yo=false writes е instead",
+            (Self::YoHelp,          Lang::Ru) => "Писать ё; --yo=false пишет е",
+            (Self::SymbolHelp,      Lang::En) => "Render this IPA symbol with Latin or Cyrillic LETTERS, e.g. --theta=t",
+            (Self::SymbolHelp,      Lang::Ru) => "Передавать символ МФА латинскими или русскими буквами, например --theta=t",
+            (Self::HelpHelp,        Lang::En) => "Print help information",
+            (Self::HelpHelp,        Lang::Ru) => "Показать справку",
+            (Self::VersionHelp,     Lang::En) => "Print version information",
+            (Self::VersionHelp,     Lang::Ru) => "Показать версию",
+            (Self::SelfTestAbout,   Lang::En) => "Run the embedded regression corpus and invariant checks",
+            (Self::SelfTestAbout,   Lang::Ru) => "Проверить встроенный корпус и инварианты",
+            (Self::SelfTestFailure, Lang::En) => "FAIL",
+            (Self::SelfTestFailure, Lang::Ru) => "ОШИБКА",
+            (Self::SelfTestChecks,  Lang::En) => "checks",
+            (Self::SelfTestChecks,  Lang::Ru) => "проверок",
+            (Self::SelfTestFailed,  Lang::En) => "failed",
+            (Self::SelfTestFailed,  Lang::Ru) => "с ошибкой",
+            (Self::ReportFailed,    Lang::En) => "cannot write report",
+            (Self::ReportFailed,    Lang::Ru) => "не удалось записать отчёт",
+            (Self::ConfigFailed,    Lang::En) => "cannot read options",
+            (Self::ConfigFailed,    Lang::Ru) => "не удалось прочитать настройки",
+            (Self::ReadFailed,      Lang::En) => "cannot read input",
+            (Self::ReadFailed,      Lang::Ru) => "не удалось прочитать ввод",
+            (Self::LineFailed,      Lang::En) => "cannot convert line",
+            (Self::LineFailed,      Lang::Ru) => "не удалось преобразовать строку",
+            (Self::ConvertAbout,    Lang::En) => "Convert every line of FILEs into DIR, keeping their paths",
+            (Self::ConvertAbout,    Lang::Ru) => "Преобразовать строки файлов в каталог DIR с сохранением путей",
+            (Self::FilesHelp,       Lang::En) => "Files with one IPA transcription per line",
+            (Self::FilesHelp,       Lang::Ru) => "Файлы с транскрипцией МФА в каждой строке",
+            (Self::OutputHelp,      Lang::En) => "Directory for the converted files",
+            (Self::OutputHelp,      Lang::Ru) => "Каталог для преобразованных файлов",
+            (Self::FileFailed,      Lang::En) => "cannot convert file",
+            (Self::FileFailed,      Lang::Ru) => "не удалось преобразовать файл",
+            (Self::OutputCollision, Lang::En) => "several files map to the same output path",
+            (Self::OutputCollision, Lang::Ru) => "несколько файлов попадают в один и тот же выходной путь",
+            (Self::CsvAbout,        Lang::En) => "Convert a CSV column and print the table with a new column appended",
+            (Self::CsvAbout,        Lang::Ru) => "Преобразовать столбец CSV и вывести таблицу с новым столбцом",
+            (Self::CsvInputHelp,    Lang::En) => "CSV file with a header row",
+            (Self::CsvInputHelp,    Lang::Ru) => "Файл CSV со строкой заголовков",
+            (Self::ColumnHelp,      Lang::En) => "Column holding the IPA transcription",
+            (Self::ColumnHelp,      Lang::Ru) => "Столбец с транскрипцией МФА",
+            (Self::NewColumnHelp,   Lang::En) => "Name of the appended column",
+            (Self::NewColumnHelp,   Lang::Ru) => "Название добавляемого столбца",
+            (Self::ColumnMissing,   Lang::En) => "no such column",
+            (Self::ColumnMissing,   Lang::Ru) => "нет такого столбца",
+            (Self::CsvFailed,       Lang::En) => "cannot convert CSV",
+            (Self::CsvFailed,       Lang::Ru) => "не удалось преобразовать CSV",
+            (Self::OptionFailed,    Lang::En) => "invalid option",
+            (Self::OptionFailed,    Lang::Ru) => "неверная настройка",
+            (Self::ReplAbout,       Lang::En) => "Convert lines interactively; commands :set, :preset, :reset, :show, :quit",
+            (Self::ReplAbout,       Lang::Ru) => "Диалоговый режим; команды :set, :preset, :reset, :show, :quit",
+            (Self::UnknownCommand,  Lang::En) => "unknown command",
+            (Self::UnknownCommand,  Lang::Ru) => "неизвестная команда",
+        }
+    }
+}
+
+#[cfg(test)]
+mod messages_tests {
+    use super::*;
+
+    #[test]
+    fn test_from_code() {
+        assert_eq!(Lang::from_code("ru"), Some(Lang::Ru));
+        assert_eq!(Lang::from_code("ru_RU.UTF-8"), Some(Lang::Ru));
+        assert_eq!(Lang::from_code("EN"), Some(Lang::En));
+        assert_eq!(Lang::from_code("de_DE"), None);
+        assert_eq!(Lang::from_code("C"), None);
+    }
+
+    #[test]
+    fn test_lang_flag() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert_eq!(lang_flag(args(&["ipa2ru", "--lang", "ru", "a"]).into_iter()), Some("ru".to_owned()));
+        assert_eq!(lang_flag(args(&["ipa2ru", "--lang=en"]).into_iter()), Some("en".to_owned()));
+        assert_eq!(lang_flag(args(&["ipa2ru", "a"]).into_iter()), None);
+    }
+}
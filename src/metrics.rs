@@ -0,0 +1,155 @@
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::cell::RefCell;
+#[cfg(feature = "std")]
+use std::sync::{Arc, OnceLock};
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+
+use crate::error::Error;
+
+pub trait Metrics: Send + Sync {
+    fn record_conversion(&self, _latency: Duration) {}
+
+    fn record_error(&self, _code: &'static str) {}
+
+    fn record_cache(&self, _hit: bool) {}
+}
+
+impl<M: Metrics + ?Sized> Metrics for &M {
+    fn record_conversion(&self, latency: Duration) {
+        (**self).record_conversion(latency)
+    }
+
+    fn record_error(&self, code: &'static str) {
+        (**self).record_error(code)
+    }
+
+    fn record_cache(&self, hit: bool) {
+        (**self).record_cache(hit)
+    }
+}
+
+#[cfg(feature = "std")]
+static METRICS: OnceLock<Box<dyn Metrics>> = OnceLock::new();
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static SCOPED_METRICS: RefCell<Option<Arc<dyn Metrics>>> = const { RefCell::new(None) };
+}
+
+#[cfg(feature = "std")]
+pub fn set_metrics(metrics: Box<dyn Metrics>) -> Result<(), Box<dyn Metrics>> {
+    METRICS.set(metrics)
+}
+
+#[cfg(feature = "std")]
+struct ScopeGuard(Option<Arc<dyn Metrics>>);
+
+#[cfg(feature = "std")]
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        SCOPED_METRICS.with(|scoped| *scoped.borrow_mut() = self.0.take());
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn with_metrics<T>(metrics: Arc<dyn Metrics>, run: impl FnOnce() -> T) -> T {
+    let _guard = ScopeGuard(SCOPED_METRICS.with(|scoped| scoped.replace(Some(metrics))));
+    run()
+}
+
+#[cfg(feature = "std")]
+fn observe(record: impl FnOnce(&dyn Metrics)) {
+    match SCOPED_METRICS.with(|scoped| scoped.borrow().clone()) {
+        Some(metrics) => record(&*metrics),
+        None => if let Some(metrics) = METRICS.get() {
+            record(&**metrics)
+        },
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn timed<T>(run: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    let start = Instant::now();
+    let result = run();
+    observe(|metrics| match &result {
+        Ok(_) => metrics.record_conversion(start.elapsed()),
+        Err(error) => metrics.record_error(error.code()),
+    });
+    result
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn timed<T>(run: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    run()
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn observe_error(code: &'static str) {
+    observe(|metrics| metrics.record_error(code));
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn observe_error(_code: &'static str) {}
+
+#[cfg(feature = "std")]
+pub(crate) fn observe_cache(hit: bool) {
+    observe(|metrics| metrics.record_cache(hit));
+}
+
+#[cfg(all(test, feature = "std"))]
+mod metrics_tests {
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::options::{RuOptions, StressMarks, VowelLength};
+    use crate::ru::Ru;
+
+    #[derive(Default)]
+    struct Counters {
+        conversions: AtomicUsize,
+        errors: Mutex<Vec<&'static str>>,
+    }
+
+    impl Metrics for Counters {
+        fn record_conversion(&self, _latency: Duration) {
+            self.conversions.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn record_error(&self, code: &'static str) {
+            self.errors.lock().unwrap().push(code);
+        }
+    }
+
+    #[test]
+    fn test_metrics() {
+        let counters = Arc::new(Counters::default());
+        with_metrics(counters.clone(), || {
+            assert!(Ru::try_from("nʲæ").is_ok());
+            assert!(Ru::try_from_xsampa("^").is_err());
+            assert!(Ru::try_from("nʲæ?").is_err());
+            let options = RuOptions { vowel_length: VowelLength::Stress, ..RuOptions::default() };
+            assert!(Ru::try_from_ipa_with("nʲæ", RuOptions { stress_marks: StressMarks::Acute, ..options }).is_err());
+        });
+        assert!(Ru::try_from("nʲæ").is_ok());
+        assert_eq!(counters.conversions.load(Ordering::Relaxed), 1);
+        assert_eq!(*counters.errors.lock().unwrap(), ["unknown-symbol", "unsupported-symbol", "option-conflict"]);
+    }
+
+    #[test]
+    fn test_nested_metrics() {
+        let outer = Arc::new(Counters::default());
+        let inner = Arc::new(Counters::default());
+        with_metrics(outer.clone(), || {
+            with_metrics(inner.clone(), || assert!(Ru::try_from("nʲæ").is_ok()));
+            assert!(Ru::try_from("nʲæ").is_ok());
+        });
+        assert_eq!(inner.conversions.load(Ordering::Relaxed), 1);
+        assert_eq!(outer.conversions.load(Ordering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,231 @@
+use alloc::string::String;
+
+pub(crate) mod arpabet;
+pub(crate) mod kirshenbaum;
+pub(crate) mod tipa;
+pub(crate) mod xsampa;
+
+use crate::error::Error;
+use crate::metrics;
+use crate::ru::Ru;
+
+const WHITESPACE: &[(&str, &str)] = &[
+    (" ", " "), ("\t", "\t"), ("\n", "\n"), ("\r", "\r"), ("\u{a0}", "\u{a0}"),
+];
+
+pub(crate) fn longest_match<'t>(
+    rest: &str,
+    offset: usize,
+    tables: &[&'t [(&'t str, &'t str)]],
+) -> Result<(&'t str, &'t str), Error> {
+    tables.iter()
+        .flat_map(|table| table.iter())
+        .chain(WHITESPACE)
+        .filter(|(symbol, _)| rest.starts_with(symbol))
+        .reduce(|best, entry| if entry.0.len() > best.0.len() { entry } else { best })
+        .copied()
+        .ok_or_else(|| Error::UnknownSymbol { offset, symbol: rest.chars().next().unwrap_or_default() })
+        .inspect_err(|error| metrics::observe_error(error.code()))
+}
+
+pub(crate) fn transcode(input: &str, tables: &[&[(&str, &str)]]) -> Result<String, Error> {
+    let mut ipa = String::with_capacity(input.len());
+    let mut offset = 0;
+    while let Some(rest) = input.get(offset..).filter(|rest| !rest.is_empty()) {
+        let (symbol, replacement) = longest_match(rest, offset, tables)?;
+        ipa.push_str(replacement);
+        offset += symbol.len();
+    }
+    Ok(ipa)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IpaStr<'a>(pub &'a str);
+
+impl<'a> IpaStr<'a> {
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl<'a> From<&'a str> for IpaStr<'a> {
+    fn from(ipa_str: &'a str) -> Self {
+        Self(ipa_str)
+    }
+}
+
+impl TryFrom<IpaStr<'_>> for Ru {
+    type Error = Error;
+
+    fn try_from(ipa: IpaStr<'_>) -> Result<Self, Self::Error> {
+        Ru::try_from_ipa(ipa.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct XsampaStr<'a>(pub &'a str);
+
+impl<'a> XsampaStr<'a> {
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl<'a> From<&'a str> for XsampaStr<'a> {
+    fn from(xsampa_str: &'a str) -> Self {
+        Self(xsampa_str)
+    }
+}
+
+impl TryFrom<XsampaStr<'_>> for Ru {
+    type Error = Error;
+
+    fn try_from(xsampa: XsampaStr<'_>) -> Result<Self, Self::Error> {
+        Ru::try_from_xsampa(xsampa.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KirshenbaumStr<'a>(pub &'a str);
+
+impl<'a> KirshenbaumStr<'a> {
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl<'a> From<&'a str> for KirshenbaumStr<'a> {
+    fn from(kirshenbaum_str: &'a str) -> Self {
+        Self(kirshenbaum_str)
+    }
+}
+
+impl TryFrom<KirshenbaumStr<'_>> for Ru {
+    type Error = Error;
+
+    fn try_from(kirshenbaum: KirshenbaumStr<'_>) -> Result<Self, Self::Error> {
+        Ru::try_from_kirshenbaum(kirshenbaum.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ArpabetStr<'a>(pub &'a str);
+
+impl<'a> ArpabetStr<'a> {
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl<'a> From<&'a str> for ArpabetStr<'a> {
+    fn from(arpabet_str: &'a str) -> Self {
+        Self(arpabet_str)
+    }
+}
+
+impl TryFrom<ArpabetStr<'_>> for Ru {
+    type Error = Error;
+
+    fn try_from(arpabet: ArpabetStr<'_>) -> Result<Self, Self::Error> {
+        Ru::try_from_arpabet(arpabet.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TipaStr<'a>(pub &'a str);
+
+impl<'a> TipaStr<'a> {
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl<'a> From<&'a str> for TipaStr<'a> {
+    fn from(tipa_str: &'a str) -> Self {
+        Self(tipa_str)
+    }
+}
+
+impl TryFrom<TipaStr<'_>> for Ru {
+    type Error = Error;
+
+    fn try_from(tipa: TipaStr<'_>) -> Result<Self, Self::Error> {
+        Ru::try_from_tipa(tipa.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CxsStr<'a>(pub &'a str);
+
+impl<'a> CxsStr<'a> {
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl<'a> From<&'a str> for CxsStr<'a> {
+    fn from(cxs_str: &'a str) -> Self {
+        Self(cxs_str)
+    }
+}
+
+impl TryFrom<CxsStr<'_>> for Ru {
+    type Error = Error;
+
+    fn try_from(cxs: CxsStr<'_>) -> Result<Self, Self::Error> {
+        Ru::try_from_cxs(cxs.as_str())
+    }
+}
+
+#[cfg(test)]
+mod notation_tests {
+    use super::*;
+
+    #[test]
+    fn test_ipa_str() {
+        assert_eq!(
+            Ru::try_from(IpaStr("nʲæ")).map(|ru| format!("{}", ru)),
+            Ok("ня".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_xsampa_str() {
+        assert_eq!(
+            Ru::try_from(XsampaStr("n'{ n'{n")).map(|ru| format!("{}", ru)),
+            Ok("ня нян".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_kirshenbaum_str() {
+        assert_eq!(
+            Ru::try_from(KirshenbaumStr("n;& n;&n")).map(|ru| format!("{}", ru)),
+            Ok("ня нян".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_arpabet_str() {
+        assert_eq!(
+            Ru::try_from(ArpabetStr("M AE1 | N AE1 P")).map(|ru| format!("{}", ru)),
+            Ok("ма нап".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_tipa_str() {
+        assert_eq!(
+            Ru::try_from(TipaStr("\\textipa{n\\super j\\ae{} n\\super j\\ae n}")).map(|ru| format!("{}", ru)),
+            Ok("ня нян".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_cxs_str() {
+        assert_eq!(
+            Ru::try_from(CxsStr("n_j{ n_j{n")).map(|ru| format!("{}", ru)),
+            Ok("ня нян".to_owned())
+        );
+    }
+}
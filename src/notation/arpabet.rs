@@ -0,0 +1,70 @@
+use core::iter;
+use alloc::string::String;
+
+use crate::error::Error;
+use crate::metrics;
+
+#[inline]
+fn phones_lookup(phone: &str) -> Option<&'static str> {
+    Some(match phone {
+        "AH0" => "ə",
+        "ER0" => "ɚ",
+        _ => match phone.trim_end_matches(['0', '1', '2']) {
+            "AA"  => "ɑ",   "AE"  => "æ",   "AH"  => "ʌ",   "AO"  => "ɔ",
+            "AW"  => "aʊ",  "AX"  => "ə",   "AXR" => "ɚ",   "AY"  => "aɪ",
+            "EH"  => "ɛ",   "ER"  => "ɝ",   "EY"  => "eɪ",  "IH"  => "ɪ",
+            "IX"  => "ɨ",   "IY"  => "i",   "OW"  => "oʊ",  "OY"  => "ɔɪ",
+            "UH"  => "ʊ",   "UW"  => "u",   "UX"  => "ʉ",
+            "B"   => "b",   "CH"  => "t͡ʃ", "D"   => "d",   "DH"  => "ð",
+            "DX"  => "ɾ",   "EL"  => "l̩",  "EM"  => "m̩",  "EN"  => "n̩",
+            "F"   => "f",   "G"   => "ɡ",   "HH"  => "h",   "JH"  => "d͡ʒ",
+            "K"   => "k",   "L"   => "l",   "M"   => "m",   "N"   => "n",
+            "NG"  => "ŋ",   "P"   => "p",   "Q"   => "ʔ",   "R"   => "ɹ",
+            "S"   => "s",   "SH"  => "ʃ",   "T"   => "t",   "TH"  => "θ",
+            "V"   => "v",   "W"   => "w",   "WH"  => "ʍ",   "Y"   => "j",
+            "Z"   => "z",   "ZH"  => "ʒ",
+            _ => return None,
+        },
+    })
+}
+
+pub(crate) fn to_ipa(arpabet_str: &str) -> Result<String, Error> {
+    let mut ipa = String::with_capacity(arpabet_str.len());
+    let mut phone_start = None;
+    for (offset, symbol) in arpabet_str.char_indices().chain(iter::once((arpabet_str.len(), ' '))) {
+        if symbol.is_whitespace() || symbol == '|' {
+            if let Some(start) = phone_start.take() {
+                let phone = &arpabet_str[start..offset];
+                let phone_ipa = phones_lookup(&phone.to_ascii_uppercase())
+                    .ok_or_else(|| Error::UnknownSymbol { offset: start, symbol: phone.chars().next().unwrap_or_default() })
+                    .inspect_err(|error| metrics::observe_error(error.code()))?;
+                ipa.push_str(phone_ipa);
+            }
+            match symbol {
+                ' ' => {},
+                '|' => ipa.push(' '),
+                separator => ipa.push(separator),
+            }
+        } else if phone_start.is_none() {
+            phone_start = Some(offset);
+        }
+    }
+    Ok(ipa)
+}
+
+#[cfg(test)]
+mod arpabet_tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ipa() {
+        assert_eq!(to_ipa("HH AH0 L OW1"), Ok("həloʊ".to_owned()));
+        assert_eq!(to_ipa("M AE1 | N AE1\nP IY0"), Ok("mæ næ\npi".to_owned()));
+        assert_eq!(to_ipa("hh ah1"), Ok("hʌ".to_owned()));
+    }
+
+    #[test]
+    fn test_unknown_phone() {
+        assert_eq!(to_ipa("HH XX1"), Err(Error::UnknownSymbol { offset: 3, symbol: 'X' }));
+    }
+}
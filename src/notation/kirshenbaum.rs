@@ -0,0 +1,37 @@
+use alloc::string::String;
+
+use crate::error::Error;
+
+use super::transcode;
+
+pub(crate) const KIRSHENBAUM: &[(&str, &str)] = &[
+    ("a",   "a"),  ("&",   "æ"),  ("A",   "ɑ"),  ("6",   "ɐ"),  ("O",   "ɔ"),
+    ("o",   "o"),  ("V",   "ʌ"),  ("E",   "ɛ"),  ("e",   "e"),  ("@",   "ə"),
+    ("I",   "ɪ"),  ("i",   "i"),  ("U",   "ʊ"),  ("u",   "u"),  ("y",   "y"),
+    ("Y",   "ø"),  ("W",   "œ"),  ("i\"", "ɨ"),  ("u\"", "ʉ"),  ("u-",  "ɯ"),
+    ("o-",  "ɤ"),
+    ("p",   "p"),  ("b",   "b"),  ("t",   "t"),  ("d",   "d"),  ("k",   "k"),
+    ("g",   "ɡ"),  ("f",   "f"),  ("v",   "v"),  ("s",   "s"),  ("z",   "z"),
+    ("h",   "h"),  ("m",   "m"),  ("n",   "n"),  ("N",   "ŋ"),  ("n^",  "ɲ"),
+    ("l",   "l"),  ("l^",  "ʎ"),  ("r",   "r"),  ("*",   "ɾ"),  ("j",   "j"),
+    ("w",   "w"),  ("S",   "ʃ"),  ("Z",   "ʒ"),  ("T",   "θ"),  ("D",   "ð"),
+    ("x",   "x"),  ("Q",   "ɣ"),  ("?",   "ʔ"),  ("c",   "c"),  ("C",   "ç"),
+    ("B",   "β"),  ("P",   "ɸ"),  ("X",   "χ"),
+    (";",   "ʲ"),  (":",   "ː"),  ("'",   "ˈ"),  (",",   "ˌ"),  ("~",   "\u{303}"),
+    ("<h>", "ʰ"),  (".",   "."),
+];
+
+pub(crate) fn to_ipa(kirshenbaum_str: &str) -> Result<String, Error> {
+    transcode(kirshenbaum_str, &[KIRSHENBAUM])
+}
+
+#[cfg(test)]
+mod kirshenbaum_tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ipa() {
+        assert_eq!(to_ipa("n;& m;&:u"), Ok("nʲæ mʲæːu".to_owned()));
+        assert_eq!(to_ipa("t<h>i\" n^"), Ok("tʰɨ ɲ".to_owned()));
+    }
+}
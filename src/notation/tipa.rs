@@ -0,0 +1,122 @@
+use alloc::string::String;
+
+use crate::error::Error;
+use crate::metrics;
+
+use super::longest_match;
+
+const TEXTIPA: &str = "\\textipa{";
+
+pub(crate) const TIPA: &[(&str, &str)] = &[
+    ("a",   "a"),  ("b",   "b"),  ("c",   "c"),  ("d",   "d"),  ("e",   "e"),
+    ("f",   "f"),  ("g",   "ɡ"),  ("h",   "h"),  ("i",   "i"),  ("j",   "j"),
+    ("k",   "k"),  ("l",   "l"),  ("m",   "m"),  ("n",   "n"),  ("o",   "o"),
+    ("p",   "p"),  ("q",   "q"),  ("r",   "r"),  ("s",   "s"),  ("t",   "t"),
+    ("u",   "u"),  ("v",   "v"),  ("w",   "w"),  ("x",   "x"),  ("y",   "y"),
+    ("z",   "z"),
+    ("0",   "ʉ"),  ("1",   "ɨ"),  ("2",   "ʌ"),  ("3",   "ɜ"),  ("4",   "ɥ"),
+    ("5",   "ɐ"),  ("6",   "ɒ"),  ("7",   "ɤ"),  ("8",   "ɵ"),  ("9",   "ɘ"),
+    ("@",   "ə"),  ("A",   "ɑ"),  ("B",   "β"),  ("C",   "ç"),  ("D",   "ð"),
+    ("E",   "ɛ"),  ("F",   "ɸ"),  ("G",   "ɣ"),  ("H",   "ɦ"),  ("I",   "ɪ"),
+    ("J",   "ʝ"),  ("K",   "ʁ"),  ("L",   "ʎ"),  ("M",   "ɱ"),  ("N",   "ŋ"),
+    ("O",   "ɔ"),  ("P",   "ʔ"),  ("Q",   "ʕ"),  ("R",   "ɾ"),  ("S",   "ʃ"),
+    ("T",   "θ"),  ("U",   "ʊ"),  ("V",   "ʋ"),  ("W",   "ɰ"),  ("X",   "χ"),
+    ("Y",   "ʏ"),  ("Z",   "ʒ"),
+    ("\\ae",               "æ"),
+    ("\\o",                "ø"),
+    ("\\oe",               "œ"),
+    ("\\textschwa",        "ə"),
+    ("\\textepsilon",      "ɛ"),
+    ("\\textturnm",        "ɯ"),
+    ("\\textbari",         "ɨ"),
+    ("\\textbaru",         "ʉ"),
+    ("\\textesh",          "ʃ"),
+    ("\\textyogh",         "ʒ"),
+    ("\\textteshlig",      "t͡ʃ"),
+    ("\\textdyoghlig",     "d͡ʒ"),
+    ("\\super j",          "ʲ"),
+    ("\\textsuperscript{j}", "ʲ"),
+    ("\\textpal",          "ʲ"),
+    ("\\textlengthmark",   "ː"),
+    (":",                  "ː"),
+    ("\\textprimstress",   "ˈ"),
+    ("\"",                 "ˈ"),
+    ("\\textsecstress",    "ˌ"),
+    ("\"\"",               "ˌ"),
+    (".",                  "."),
+    ("{",                  ""),
+    ("}",                  ""),
+];
+
+fn decode_body(body: &str, base_offset: usize) -> Result<String, Error> {
+    let mut ipa = String::with_capacity(body.len());
+    let mut offset = 0;
+    while let Some(rest) = body.get(offset..).filter(|rest| !rest.is_empty()) {
+        let (symbol, replacement) = longest_match(rest, base_offset + offset, &[TIPA])?;
+        ipa.push_str(replacement);
+        offset += symbol.len();
+        if symbol.strip_prefix('\\').is_some_and(|name| name.chars().all(|letter| letter.is_ascii_alphabetic())) {
+            offset += body[offset..].len() - body[offset..].trim_start_matches(' ').len();
+        }
+    }
+    Ok(ipa)
+}
+
+fn closing_brace(text: &str) -> Option<usize> {
+    text.char_indices()
+        .scan(1usize, |depth, (i, symbol)| {
+            match symbol {
+                '{' => *depth += 1,
+                '}' => *depth -= 1,
+                _ => {},
+            }
+            Some((i, *depth))
+        })
+        .find(|&(_, depth)| depth == 0)
+        .map(|(i, _)| i)
+}
+
+pub(crate) fn to_ipa(tipa_str: &str) -> Result<String, Error> {
+    let mut ipa = String::with_capacity(tipa_str.len());
+    let mut offset = 0;
+    while let Some(start) = tipa_str[offset..].find(TEXTIPA).map(|start| offset + start) {
+        ipa.push_str(&tipa_str[offset..start]);
+        let body_start = start + TEXTIPA.len();
+        let body_end = closing_brace(&tipa_str[body_start..])
+            .map(|end| body_start + end)
+            .ok_or(Error::UnknownSymbol { offset: start, symbol: '\\' })
+            .inspect_err(|error| metrics::observe_error(error.code()))?;
+        ipa.push_str(&decode_body(&tipa_str[body_start..body_end], body_start)?);
+        offset = body_end + 1;
+    }
+    ipa.push_str(&tipa_str[offset..]);
+    Ok(ipa)
+}
+
+#[cfg(test)]
+mod tipa_tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ipa() {
+        assert_eq!(to_ipa("\\textipa{n\\super j\\ae{} m\\textsuperscript{j}\\ae:u}"), Ok("nʲæ mʲæːu".to_owned()));
+        assert_eq!(to_ipa("\\textipa{\"h@loU}"), Ok("ˈhəloʊ".to_owned()));
+        assert_eq!(to_ipa("\\textipa{\\ae n}"), Ok("æn".to_owned()));
+        assert_eq!(to_ipa("\\textipa{n\\super j n}"), Ok("nʲ n".to_owned()));
+    }
+
+    #[test]
+    fn test_plain_text_passes_through() {
+        assert_eq!(to_ipa("nʲæ \\textipa{n\\super j\\ae}"), Ok("nʲæ nʲæ".to_owned()));
+    }
+
+    #[test]
+    fn test_unbalanced() {
+        assert_eq!(to_ipa("\\textipa{n"), Err(Error::UnknownSymbol { offset: 0, symbol: '\\' }));
+    }
+
+    #[test]
+    fn test_unknown_macro() {
+        assert_eq!(to_ipa("\\textipa{n\\foo}"), Err(Error::UnknownSymbol { offset: 10, symbol: '\\' }));
+    }
+}
@@ -0,0 +1,60 @@
+use alloc::string::String;
+
+use crate::error::Error;
+
+use super::transcode;
+
+pub(crate) const XSAMPA: &[(&str, &str)] = &[
+    ("a",   "a"),  ("e",   "e"),  ("i",   "i"),  ("o",   "o"),  ("u",   "u"),
+    ("y",   "y"),  ("{",   "æ"),  ("@",   "ə"),  ("@\\", "ɘ"),  ("E",   "ɛ"),
+    ("O",   "ɔ"),  ("V",   "ʌ"),  ("A",   "ɑ"),  ("I",   "ɪ"),  ("U",   "ʊ"),
+    ("Y",   "ʏ"),  ("2",   "ø"),  ("9",   "œ"),  ("1",   "ɨ"),  ("}",   "ʉ"),
+    ("M",   "ɯ"),  ("7",   "ɤ"),  ("8",   "ɵ"),  ("6",   "ɐ"),  ("3",   "ɜ"),
+    ("Q",   "ɒ"),  ("&",   "ɶ"),
+    ("p",   "p"),  ("b",   "b"),  ("t",   "t"),  ("d",   "d"),  ("k",   "k"),
+    ("g",   "ɡ"),  ("f",   "f"),  ("v",   "v"),  ("s",   "s"),  ("z",   "z"),
+    ("h",   "h"),  ("m",   "m"),  ("n",   "n"),  ("J",   "ɲ"),  ("N",   "ŋ"),
+    ("l",   "l"),  ("r",   "r"),  ("j",   "j"),  ("w",   "w"),  ("S",   "ʃ"),
+    ("Z",   "ʒ"),  ("T",   "θ"),  ("D",   "ð"),  ("x",   "x"),  ("G",   "ɣ"),
+    ("R",   "ʁ"),  ("r\\", "ɹ"),  ("4",   "ɾ"),  ("L",   "ʎ"),  ("5",   "ɫ"),
+    ("X",   "χ"),  ("?",   "ʔ"),  ("C",   "ç"),  ("j\\", "ʝ"),  ("s\\", "ɕ"),
+    ("z\\", "ʑ"),  ("h\\", "ɦ"),  ("H",   "ɥ"),  ("B",   "β"),  ("F",   "ɱ"),
+    ("P",   "ʋ"),  ("K",   "ɬ"),
+    ("'",   "ʲ"),  ("_j",  "ʲ"),  (":",   "ː"),  ("\"",  "ˈ"),  ("%",   "ˌ"),
+    ("_h",  "ʰ"),  ("=",   "\u{329}"), ("_0", "\u{325}"), ("~", "\u{303}"),
+    ("_d",  "\u{32a}"), ("_}", "\u{31a}"), (".", "."),
+];
+
+pub(crate) const CXS: &[(&str, &str)] = &[
+    ("'",   "ʼ"),  ("I\\", "ᵻ"),  ("U\\", "ᵿ"),  ("3\\", "ɞ"),  ("`",   "˞"),
+];
+
+pub(crate) fn to_ipa(xsampa_str: &str) -> Result<String, Error> {
+    transcode(xsampa_str, &[XSAMPA])
+}
+
+pub(crate) fn cxs_to_ipa(cxs_str: &str) -> Result<String, Error> {
+    transcode(cxs_str, &[CXS, XSAMPA])
+}
+
+#[cfg(test)]
+mod xsampa_tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ipa() {
+        assert_eq!(to_ipa("n'{ m'{:u"), Ok("nʲæ mʲæːu".to_owned()));
+        assert_eq!(to_ipa("r\\@ s\\"), Ok("ɹə ɕ".to_owned()));
+    }
+
+    #[test]
+    fn test_cxs_to_ipa() {
+        assert_eq!(cxs_to_ipa("n_j{ k'I\\"), Ok("nʲæ kʼᵻ".to_owned()));
+        assert_eq!(to_ipa("n'{"), Ok("nʲæ".to_owned()));
+    }
+
+    #[test]
+    fn test_unknown_symbol() {
+        assert_eq!(to_ipa("n'{ ^"), Err(Error::UnknownSymbol { offset: 4, symbol: '^' }));
+    }
+}
@@ -0,0 +1,634 @@
+use alloc::{borrow::ToOwned, format, string::{String, ToString}, vec, vec::Vec};
+use core::{fmt, str::FromStr};
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::error::Error;
+use crate::preset;
+
+macro_rules! option_names {
+    ($($option:ident { $($variant:ident => $name:literal),* $(,)? })*) => {$(
+        impl $option {
+            pub const NAMES: &'static [&'static str] = &[$($name),*];
+
+            pub(crate) fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)*
+                }
+            }
+
+            pub(crate) fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $($name => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+    )*};
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum NormalizationForm {
+    #[default]
+    Nfc,
+    Nfd,
+}
+
+impl NormalizationForm {
+    pub(crate) fn apply(self, text: &str) -> String {
+        match self {
+            Self::Nfc => text.nfc().collect(),
+            Self::Nfd => text.nfd().collect(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ReleaseModifiers {
+    #[default]
+    Reject,
+    Drop,
+    ReducedVowel,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum JotAfterConsonant {
+    #[default]
+    HardSign,
+    MorphemeBoundary,
+    SoftSign,
+    Auto,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum JotSpelling {
+    Omit,
+    ShortI,
+    I,
+}
+
+impl JotSpelling {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Omit   => "",
+            Self::ShortI => "й",
+            Self::I      => "и",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct JotRules {
+    pub onset: JotSpelling,
+    pub coda: JotSpelling,
+    pub between_consonants: JotSpelling,
+    pub word_initial: JotSpelling,
+}
+
+impl Default for JotRules {
+    fn default() -> Self {
+        Self {
+            onset: JotSpelling::Omit,
+            coda: JotSpelling::ShortI,
+            between_consonants: JotSpelling::ShortI,
+            word_initial: JotSpelling::ShortI,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum QSpelling {
+    #[default]
+    Ch,
+    ChSoftSign,
+}
+
+impl QSpelling {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Ch         => "ч",
+            Self::ChSoftSign => "чь",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct QRules {
+    pub before_consonant: QSpelling,
+    pub word_final: QSpelling,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Orthography {
+    #[default]
+    Standard,
+    Phonetic,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum TseVowel {
+    #[default]
+    I,
+    Y,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum YoPolicy {
+    #[default]
+    Yo,
+    Ye,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
+pub enum ClusterStrategy {
+    #[default]
+    Keep,
+    EpentheticY,
+    EpentheticE,
+    Hyphen,
+}
+
+impl ClusterStrategy {
+    pub(crate) fn is_epenthetic(self) -> bool {
+        matches!(self, Self::EpentheticY | Self::EpentheticE)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct ClusterRules {
+    pub max_len: usize,
+    pub strategy: ClusterStrategy,
+}
+
+impl Default for ClusterRules {
+    fn default() -> Self {
+        Self {
+            max_len: 3,
+            strategy: ClusterStrategy::Keep,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum StressMarks {
+    #[default]
+    Omit,
+    Acute,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum VowelReduction {
+    #[default]
+    None,
+    Akanye,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Hiatus {
+    #[default]
+    Keep,
+    InsertJot,
+    Hyphen,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum InitialE {
+    #[default]
+    E,
+    Ye,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Geminates {
+    #[default]
+    Double,
+    Single,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum FinalDevoicing {
+    #[default]
+    Literal,
+    /// Lossy: voices every word-final voiceless obstruent, so "кот" comes out as "код".
+    Restore,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum HushingSoftSign {
+    #[default]
+    Suppress,
+    Grammatical,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum VowelLength {
+    #[default]
+    Double,
+    Stress,
+    Macron,
+    Colon,
+    Drop,
+}
+
+impl VowelLength {
+    pub(crate) fn tail(self) -> Option<&'static str> {
+        match self {
+            Self::Double => None,
+            Self::Stress => Some("\u{301}"),
+            Self::Macron => Some("\u{304}"),
+            Self::Colon  => Some(":"),
+            Self::Drop   => Some(""),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Hyphenation {
+    #[default]
+    None,
+    Marked,
+    Computed,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Affricates {
+    #[default]
+    Digraph,
+    Decomposed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Preset {
+    English,
+    German,
+    GermanModern,
+    French,
+    Japanese,
+    Mandarin,
+    MandarinToneNumbers,
+    Korean,
+    Spanish,
+    Arabic,
+    Hindustani,
+    Polish,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct RuOptions {
+    pub normalization: NormalizationForm,
+    pub release_modifiers: ReleaseModifiers,
+    pub jot_after_consonant: JotAfterConsonant,
+    pub jot_rules: JotRules,
+    pub q_rules: QRules,
+    pub orthography: Orthography,
+    pub tse_vowel: TseVowel,
+    pub yo_policy: YoPolicy,
+    pub cluster_rules: ClusterRules,
+    pub stress_marks: StressMarks,
+    pub vowel_reduction: VowelReduction,
+    pub hiatus: Hiatus,
+    pub initial_e: InitialE,
+    pub geminates: Geminates,
+    pub final_devoicing: FinalDevoicing,
+    pub hushing_soft_sign: HushingSoftSign,
+    pub vowel_length: VowelLength,
+    pub capitalize_words: bool,
+    pub hyphenation: Hyphenation,
+    pub affricates: Affricates,
+    pub overrides: Vec<(String, String)>,
+}
+
+option_names! {
+    NormalizationForm { Nfc => "nfc", Nfd => "nfd" }
+    ReleaseModifiers { Reject => "reject", Drop => "drop", ReducedVowel => "reduced-vowel" }
+    JotAfterConsonant {
+        HardSign => "hard-sign", MorphemeBoundary => "morpheme-boundary", SoftSign => "soft-sign", Auto => "auto",
+    }
+    JotSpelling { Omit => "omit", ShortI => "short-i", I => "i" }
+    QSpelling { Ch => "ch", ChSoftSign => "ch-soft-sign" }
+    Orthography { Standard => "standard", Phonetic => "phonetic" }
+    TseVowel { I => "i", Y => "y" }
+    YoPolicy { Yo => "yo", Ye => "ye" }
+    ClusterStrategy { Keep => "keep", EpentheticY => "epenthetic-y", EpentheticE => "epenthetic-e", Hyphen => "hyphen" }
+    StressMarks { Omit => "omit", Acute => "acute" }
+    VowelReduction { None => "none", Akanye => "akanye" }
+    Hiatus { Keep => "keep", InsertJot => "insert-jot", Hyphen => "hyphen" }
+    InitialE { E => "e", Ye => "ye" }
+    Geminates { Double => "double", Single => "single" }
+    FinalDevoicing { Literal => "literal", Restore => "restore" }
+    HushingSoftSign { Suppress => "suppress", Grammatical => "grammatical" }
+    VowelLength { Double => "double", Stress => "stress", Macron => "macron", Colon => "colon", Drop => "drop" }
+    Hyphenation { None => "none", Marked => "marked", Computed => "computed" }
+    Affricates { Digraph => "digraph", Decomposed => "decomposed" }
+    Preset {
+        English => "english", German => "german", GermanModern => "german-modern", French => "french",
+        Japanese => "japanese", Mandarin => "mandarin", MandarinToneNumbers => "mandarin-tone-numbers", Korean => "korean",
+        Spanish => "spanish", Arabic => "arabic", Hindustani => "hindustani", Polish => "polish",
+    }
+}
+
+impl fmt::Display for Preset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Preset {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::from_name(name).ok_or_else(|| Error::InvalidOptions { reason: format!("unknown preset {:?}", name) })
+    }
+}
+
+fn escape(field: &str) -> String {
+    field.chars().fold(String::new(), |mut escaped, symbol| {
+        match symbol {
+            '%' | ';' | '=' | '>' => escaped.push_str(&format!("%{:02X}", u32::from(symbol))),
+            _                     => escaped.push(symbol),
+        }
+        escaped
+    })
+}
+
+fn unescape(field: &str) -> Option<String> {
+    let mut parts = field.split('%');
+    let mut unescaped = parts.next()?.to_owned();
+    for part in parts {
+        unescaped.push(char::from(u8::from_str_radix(part.get(..2)?, 16).ok()?));
+        unescaped.push_str(&part[2..]);
+    }
+    Some(unescaped)
+}
+
+impl RuOptions {
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(source: &str) -> Result<Self, Error> {
+        toml::from_str::<Self>(source)
+            .map_err(|error| Error::InvalidOptions { reason: error.to_string() })
+            .and_then(|options| options.validate().map(|()| options))
+    }
+
+    #[cfg(feature = "json")]
+    pub fn from_json_str(source: &str) -> Result<Self, Error> {
+        serde_json::from_str::<Self>(source)
+            .map_err(|error| Error::InvalidOptions { reason: error.to_string() })
+            .and_then(|options| options.validate().map(|()| options))
+    }
+
+    pub fn preset(preset: Preset) -> Self {
+        preset::options(preset)
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        match (self.stress_marks, self.vowel_length) {
+            (StressMarks::Acute, VowelLength::Stress) => {
+                Err(Error::OptionConflict { first: "stress-marks", second: "vowel-length" })
+            },
+            _ => Ok(()),
+        }
+    }
+
+    pub fn practical() -> Self {
+        Self {
+            yo_policy: YoPolicy::Ye,
+            vowel_length: VowelLength::Drop,
+            overrides: vec![("h".to_owned(), "х".to_owned()), ("w".to_owned(), "в".to_owned())],
+            ..Self::default()
+        }
+    }
+
+    pub fn keys() -> Vec<(&'static str, &'static [&'static str])> {
+        Self::default().fields().into_iter().map(|(key, _, names)| (key, names)).collect()
+    }
+
+    pub(crate) fn fields(&self) -> Vec<(&'static str, String, &'static [&'static str])> {
+        let mut fields = vec![
+            ("normalization",           self.normalization.name().to_owned(),                NormalizationForm::NAMES),
+            ("release_modifiers",       self.release_modifiers.name().to_owned(),            ReleaseModifiers::NAMES),
+            ("jot_after_consonant",     self.jot_after_consonant.name().to_owned(),          JotAfterConsonant::NAMES),
+            ("jot_onset",               self.jot_rules.onset.name().to_owned(),              JotSpelling::NAMES),
+            ("jot_coda",                self.jot_rules.coda.name().to_owned(),               JotSpelling::NAMES),
+            ("jot_between_consonants",  self.jot_rules.between_consonants.name().to_owned(), JotSpelling::NAMES),
+            ("jot_word_initial",        self.jot_rules.word_initial.name().to_owned(),       JotSpelling::NAMES),
+            ("q_before_consonant",      self.q_rules.before_consonant.name().to_owned(),     QSpelling::NAMES),
+            ("q_word_final",            self.q_rules.word_final.name().to_owned(),           QSpelling::NAMES),
+            ("orthography",             self.orthography.name().to_owned(),                  Orthography::NAMES),
+            ("tse_vowel",               self.tse_vowel.name().to_owned(),                    TseVowel::NAMES),
+            ("yo_policy",               self.yo_policy.name().to_owned(),                    YoPolicy::NAMES),
+            ("cluster_max_len",         self.cluster_rules.max_len.to_string(),              &[]),
+            ("cluster_strategy",        self.cluster_rules.strategy.name().to_owned(),       ClusterStrategy::NAMES),
+            ("stress_marks",            self.stress_marks.name().to_owned(),                 StressMarks::NAMES),
+            ("vowel_reduction",         self.vowel_reduction.name().to_owned(),              VowelReduction::NAMES),
+            ("hiatus",                  self.hiatus.name().to_owned(),                       Hiatus::NAMES),
+            ("initial_e",               self.initial_e.name().to_owned(),                    InitialE::NAMES),
+            ("geminates",               self.geminates.name().to_owned(),                    Geminates::NAMES),
+            ("final_devoicing",         self.final_devoicing.name().to_owned(),              FinalDevoicing::NAMES),
+            ("hushing_soft_sign",       self.hushing_soft_sign.name().to_owned(),            HushingSoftSign::NAMES),
+            ("vowel_length",            self.vowel_length.name().to_owned(),                 VowelLength::NAMES),
+            ("capitalize_words",        self.capitalize_words.to_string(),                   &["true", "false"]),
+            ("hyphenation",             self.hyphenation.name().to_owned(),                  Hyphenation::NAMES),
+            ("affricates",              self.affricates.name().to_owned(),                   Affricates::NAMES),
+        ];
+        fields.extend(self.overrides.iter().map(|(ipa, ru)| ("override", format!("{}>{}", escape(ipa), escape(ru)), &[][..])));
+        fields
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        if key != "override" && self.fields().iter().all(|(name, _, _)| *name != key) {
+            return Err(Error::InvalidOptions { reason: format!("unknown option {}", key) });
+        }
+        self.set_field(key, value)
+            .ok_or_else(|| Error::InvalidOptions { reason: format!("invalid value {:?} for {}", value, key) })
+    }
+
+    pub(crate) fn set_field(&mut self, key: &str, value: &str) -> Option<()> {
+        match key {
+            "normalization"          => self.normalization = NormalizationForm::from_name(value)?,
+            "release_modifiers"      => self.release_modifiers = ReleaseModifiers::from_name(value)?,
+            "jot_after_consonant"    => self.jot_after_consonant = JotAfterConsonant::from_name(value)?,
+            "jot_onset"              => self.jot_rules.onset = JotSpelling::from_name(value)?,
+            "jot_coda"               => self.jot_rules.coda = JotSpelling::from_name(value)?,
+            "jot_between_consonants" => self.jot_rules.between_consonants = JotSpelling::from_name(value)?,
+            "jot_word_initial"       => self.jot_rules.word_initial = JotSpelling::from_name(value)?,
+            "q_before_consonant"     => self.q_rules.before_consonant = QSpelling::from_name(value)?,
+            "q_word_final"           => self.q_rules.word_final = QSpelling::from_name(value)?,
+            "orthography"            => self.orthography = Orthography::from_name(value)?,
+            "tse_vowel"              => self.tse_vowel = TseVowel::from_name(value)?,
+            "yo_policy"              => self.yo_policy = YoPolicy::from_name(value)?,
+            "cluster_max_len"        => self.cluster_rules.max_len = value.parse().ok()?,
+            "cluster_strategy"       => self.cluster_rules.strategy = ClusterStrategy::from_name(value)?,
+            "stress_marks"           => self.stress_marks = StressMarks::from_name(value)?,
+            "vowel_reduction"        => self.vowel_reduction = VowelReduction::from_name(value)?,
+            "hiatus"                 => self.hiatus = Hiatus::from_name(value)?,
+            "initial_e"              => self.initial_e = InitialE::from_name(value)?,
+            "geminates"              => self.geminates = Geminates::from_name(value)?,
+            "final_devoicing"        => self.final_devoicing = FinalDevoicing::from_name(value)?,
+            "hushing_soft_sign"      => self.hushing_soft_sign = HushingSoftSign::from_name(value)?,
+            "vowel_length"           => self.vowel_length = VowelLength::from_name(value)?,
+            "capitalize_words"       => self.capitalize_words = value.parse().ok()?,
+            "hyphenation"            => self.hyphenation = Hyphenation::from_name(value)?,
+            "affricates"             => self.affricates = Affricates::from_name(value)?,
+            "override"               => {
+                let (ipa, ru) = value.split_once('>')?;
+                self.overrides.push((unescape(ipa)?, unescape(ru)?))
+            },
+            _                        => return None,
+        }
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod options_tests {
+    use super::*;
+
+    #[test]
+    fn test_fields_round_trip() {
+        let options = RuOptions {
+            jot_rules: JotRules { onset: JotSpelling::I, ..JotRules::default() },
+            cluster_rules: ClusterRules { max_len: 2, strategy: ClusterStrategy::Hyphen },
+            vowel_length: VowelLength::Stress,
+            capitalize_words: true,
+            overrides: vec![("θ".to_owned(), "т".to_owned()), ("ð;x=1".to_owned(), "д>%".to_owned())],
+            ..RuOptions::default()
+        };
+        let mut restored = RuOptions::default();
+        options.fields().iter().for_each(|(key, value, _)| assert_eq!(restored.set_field(key, value), Some(())));
+        assert_eq!(restored, options);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_from_json_str() {
+        let options = RuOptions::from_json_str(r#"{"yo_policy":"ye","overrides":[["θ","т"]]}"#);
+        assert_eq!(options, Ok(RuOptions {
+            yo_policy: YoPolicy::Ye,
+            overrides: vec![("θ".to_owned(), "т".to_owned())],
+            ..RuOptions::default()
+        }));
+        assert!(matches!(RuOptions::from_json_str(r#"{"yo_policy":"Ye"}"#), Err(Error::InvalidOptions { reason: _ })));
+        assert!(matches!(RuOptions::from_json_str(r#"{"yo_polcy":"ye"}"#), Err(Error::InvalidOptions { reason: _ })));
+        assert!(matches!(
+            RuOptions::from_json_str(r#"{"stress_marks":"acute","vowel_length":"stress"}"#),
+            Err(Error::OptionConflict { first: _, second: _ })
+        ));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_serde_names() {
+        assert_eq!(serde_json::to_string(&JotSpelling::ShortI).ok(), Some(format!("{:?}", JotSpelling::ShortI.name())));
+        assert_eq!(serde_json::to_string(&ClusterStrategy::EpentheticY).ok(), Some(r#""epenthetic-y""#.to_owned()));
+        assert_eq!(serde_json::from_str::<Preset>(r#""mandarin-tone-numbers""#).ok(), Some(Preset::MandarinToneNumbers));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml_str() {
+        let options = RuOptions::from_toml_str("yo_policy = \"ye\"\n\n[cluster_rules]\nmax_len = 2\nstrategy = \"hyphen\"\n");
+        assert_eq!(options, Ok(RuOptions {
+            yo_policy: YoPolicy::Ye,
+            cluster_rules: ClusterRules { max_len: 2, strategy: ClusterStrategy::Hyphen },
+            ..RuOptions::default()
+        }));
+        let options = RuOptions::from_toml_str("[jot_rules]\nonset = \"i\"\n\n[cluster_rules]\nstrategy = \"hyphen\"\n");
+        assert_eq!(options, Ok(RuOptions {
+            jot_rules: JotRules { onset: JotSpelling::I, ..JotRules::default() },
+            cluster_rules: ClusterRules { strategy: ClusterStrategy::Hyphen, ..ClusterRules::default() },
+            ..RuOptions::default()
+        }));
+        assert!(RuOptions::from_toml_str("[cluster_rules]\nmax_length = 2\n").is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(RuOptions::default().validate(), Ok(()));
+        let options = RuOptions { stress_marks: StressMarks::Acute, vowel_length: VowelLength::Stress, ..RuOptions::default() };
+        assert_eq!(options.validate(), Err(Error::OptionConflict { first: "stress-marks", second: "vowel-length" }));
+    }
+
+    #[test]
+    fn test_set_field_rejects_unknown() {
+        let mut options = RuOptions::default();
+        assert_eq!(options.set_field("geminates", "triple"), None);
+        assert_eq!(options.set_field("gemination", "single"), None);
+        assert_eq!(options.set_field("cluster_max_len", "-1"), None);
+        assert_eq!(options, RuOptions::default());
+    }
+
+    #[test]
+    fn test_set() {
+        let mut options = RuOptions::default();
+        assert_eq!(options.set("geminates", "single"), Ok(()));
+        assert_eq!(options.set("override", "θ>т"), Ok(()));
+        assert_eq!(options.set("geminates", "triple"), Err(Error::InvalidOptions {
+            reason: "invalid value \"triple\" for geminates".to_owned(),
+        }));
+        assert_eq!(options.set("gemination", "single"), Err(Error::InvalidOptions {
+            reason: "unknown option gemination".to_owned(),
+        }));
+        assert_eq!(options, RuOptions {
+            geminates: Geminates::Single,
+            overrides: vec![("θ".to_owned(), "т".to_owned())],
+            ..RuOptions::default()
+        });
+    }
+
+    #[test]
+    fn test_preset_names() {
+        assert_eq!("mandarin-tone-numbers".parse(), Ok(Preset::MandarinToneNumbers));
+        assert_eq!("german-modern".parse(), Ok(Preset::GermanModern));
+        assert_eq!(Preset::English.to_string(), "english");
+        assert!("klingon".parse::<Preset>().is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+use crate::error::Error;
+use crate::options::RuOptions;
+use crate::ru::PhonemeSeq;
+
+#[derive(Clone, Debug)]
+pub struct BatchPipeline {
+    options: RuOptions,
+    capacity: usize,
+}
+
+impl BatchPipeline {
+    pub fn new(capacity: usize) -> Self {
+        Self { options: RuOptions::default(), capacity }
+    }
+
+    pub fn with_options(self, options: RuOptions) -> Self {
+        Self { options, ..self }
+    }
+
+    pub fn run<I>(self, records: I) -> BatchOutput
+    where
+        I: IntoIterator<Item = String>,
+        I::IntoIter: Send + 'static,
+    {
+        let (parsed_tx, parsed_rx) = mpsc::sync_channel(self.capacity);
+        let (converted_tx, converted_rx) = mpsc::sync_channel(self.capacity);
+        let (rendered_tx, rendered_rx) = mpsc::sync_channel(self.capacity);
+        let records = records.into_iter();
+        let options = self.options;
+        let (parse_options, convert_options) = (options.clone(), options.clone());
+        thread::spawn(move || {
+            records
+                .map(|record| parse_options.validate().and_then(|()| PhonemeSeq::tokenized(&record, &parse_options)))
+                .map_while(|parsed| parsed_tx.send(parsed).ok())
+                .for_each(drop)
+        });
+        thread::spawn(move || {
+            parsed_rx
+                .into_iter()
+                .map(|parsed| parsed.map(|seq| seq.converted(&convert_options, &[])))
+                .try_for_each(|converted| converted_tx.send(converted))
+        });
+        thread::spawn(move || {
+            converted_rx
+                .into_iter()
+                .map(|converted| {
+                    let rendered = converted.and_then(|seq| Ok(seq.render(&options)?));
+                    rendered.map(|rendered| options.normalization.apply(&rendered))
+                })
+                .try_for_each(|rendered| rendered_tx.send(rendered))
+        });
+        BatchOutput(rendered_rx)
+    }
+}
+
+pub struct BatchOutput(Receiver<Result<String, Error>>);
+
+impl Iterator for BatchOutput {
+    type Item = Result<String, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.recv().ok()
+    }
+}
+
+#[cfg(test)]
+mod pipeline_tests {
+    use super::*;
+    use crate::options::Hyphenation;
+    use crate::ru::Ru;
+
+    #[test]
+    fn test_batch() {
+        let records = (0..1000).map(|i| if i % 2 == 0 { "nʲæ".to_owned() } else { "mʲæːu".to_owned() });
+        let output = BatchPipeline::new(4).run(records).collect::<Vec<_>>();
+        assert_eq!(output.len(), 1000);
+        output.iter().enumerate().for_each(|(i, rendered)| {
+            assert_eq!(rendered.as_deref(), Ok(if i % 2 == 0 { "ня" } else { "мяау" }));
+        });
+    }
+
+    #[test]
+    fn test_batch_options() {
+        let options = RuOptions { hyphenation: Hyphenation::Computed, capitalize_words: true, ..RuOptions::default() };
+        let records = vec!["nʲæmæ pʲænʲæ".to_owned(), "mʲæːu".to_owned()];
+        let expected = records.iter()
+            .map(|record| Ru::try_from_ipa_with(record, options.clone()).map(Ru::into_string))
+            .collect::<Vec<_>>();
+        assert_eq!(BatchPipeline::new(1).with_options(options).run(records).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_batch_errors() {
+        let output = BatchPipeline::new(0).run(vec!["nʲæ".to_owned(), "?".to_owned()]).collect::<Vec<_>>();
+        assert_eq!(output[0].as_deref(), Ok("ня"));
+        assert!(matches!(output[1], Err(Error::UnsupportedSymbol { offset: 0, char_offset: 0, symbol: _ })));
+    }
+}
@@ -0,0 +1,134 @@
+use std::borrow::ToOwned;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::converter::Converter;
+use crate::error::Error;
+use crate::metrics;
+use crate::ru::Ru;
+
+type Overlay = Vec<(String, String)>;
+
+#[derive(Debug)]
+pub struct ConverterPool {
+    base: Arc<Converter>,
+    size: usize,
+    converters: Mutex<Vec<(Overlay, Arc<Converter>)>>,
+}
+
+impl ConverterPool {
+    pub fn new(converter: Converter, size: usize) -> Self {
+        Self { base: Arc::new(converter), size, converters: Mutex::new(Vec::with_capacity(size)) }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn len(&self) -> usize {
+        self.converters.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn converter(&self, overlay: &[(&str, &str)]) -> Result<Arc<Converter>, Error> {
+        if overlay.is_empty() {
+            return Ok(Arc::clone(&self.base));
+        }
+        let key = overlay.iter().map(|(key, value)| ((*key).to_owned(), (*value).to_owned())).collect::<Overlay>();
+        let mut converters = self.converters.lock().unwrap_or_else(PoisonError::into_inner);
+        let position = converters.iter().position(|(cached, _)| *cached == key);
+        metrics::observe_cache(position.is_some());
+        if let Some(position) = position {
+            let entry = converters.remove(position);
+            let converter = Arc::clone(&entry.1);
+            converters.push(entry);
+            return Ok(converter);
+        }
+        let converter = Arc::new(self.base.overlay(overlay)?);
+        if self.size > 0 {
+            if converters.len() == self.size {
+                converters.remove(0);
+            }
+            converters.push((key, Arc::clone(&converter)));
+        }
+        Ok(converter)
+    }
+
+    pub fn convert(&self, ipa_str: &str, overlay: &[(&str, &str)]) -> Result<Ru, Error> {
+        self.converter(overlay)?.convert(ipa_str)
+    }
+}
+
+#[cfg(test)]
+mod pool_tests {
+    use super::*;
+    use std::thread;
+    use crate::metrics::Metrics;
+
+    #[test]
+    fn test_overlay() {
+        let pool = ConverterPool::new(Converter::new(), 2);
+        assert_eq!(pool.convert("nʲo", &[]).map(|ru| ru.to_string()), Ok("нё".to_owned()));
+        assert_eq!(pool.convert("nʲo", &[("yo_policy", "ye")]).map(|ru| ru.to_string()), Ok("не".to_owned()));
+        assert_eq!(pool.converter(&[]).map(|converter| converter.options().clone()), Ok(Default::default()));
+        assert!(matches!(pool.convert("nʲo", &[("yo_policy", "yes")]), Err(Error::InvalidOptions { reason: _ })));
+        assert!(matches!(
+            pool.convert("nʲo", &[("stress_marks", "acute"), ("vowel_length", "stress")]),
+            Err(Error::OptionConflict { first: _, second: _ })
+        ));
+    }
+
+    #[test]
+    fn test_reuse() {
+        let pool = ConverterPool::new(Converter::new(), 1);
+        let ye = pool.converter(&[("yo_policy", "ye")]).unwrap();
+        assert!(Arc::ptr_eq(&ye, &pool.converter(&[("yo_policy", "ye")]).unwrap()));
+        assert_eq!(pool.len(), 1);
+        let acute = pool.converter(&[("stress_marks", "acute")]).unwrap();
+        assert_eq!(pool.len(), 1);
+        assert!(Arc::ptr_eq(&acute, &pool.converter(&[("stress_marks", "acute")]).unwrap()));
+        assert!(!Arc::ptr_eq(&ye, &pool.converter(&[("yo_policy", "ye")]).unwrap()));
+        let unpooled = ConverterPool::new(Converter::new(), 0);
+        assert_eq!(unpooled.convert("nʲo", &[("yo_policy", "ye")]).map(|ru| ru.to_string()), Ok("не".to_owned()));
+        assert!(unpooled.is_empty());
+    }
+
+    #[test]
+    fn test_threads() {
+        let pool = ConverterPool::new(Converter::new(), 4);
+        thread::scope(|scope| {
+            (0..8).for_each(|i| {
+                let pool = &pool;
+                scope.spawn(move || {
+                    let overlay = if i % 2 == 0 { [("yo_policy", "ye")] } else { [("yo_policy", "yo")] };
+                    let expected = if i % 2 == 0 { "не" } else { "нё" };
+                    assert_eq!(pool.convert("nʲo", &overlay).map(|ru| ru.to_string()), Ok(expected.to_owned()));
+                });
+            })
+        });
+        assert_eq!(pool.len(), 2);
+    }
+
+    #[test]
+    fn test_cache_metrics() {
+        struct Hits(Mutex<Vec<bool>>);
+
+        impl Metrics for Hits {
+            fn record_cache(&self, hit: bool) {
+                self.0.lock().unwrap().push(hit);
+            }
+        }
+
+        let hits = Arc::new(Hits(Mutex::new(Vec::new())));
+        let pool = ConverterPool::new(Converter::new(), 1);
+        metrics::with_metrics(hits.clone(), || {
+            assert!(pool.converter(&[("yo_policy", "ye")]).is_ok());
+            assert!(pool.converter(&[("yo_policy", "ye")]).is_ok());
+            assert!(pool.converter(&[]).is_ok());
+            assert!(pool.converter(&[("stress_marks", "acute")]).is_ok());
+        });
+        assert_eq!(*hits.0.lock().unwrap(), [false, true, false]);
+    }
+}
@@ -0,0 +1 @@
+pub use crate::{convert, Converter, Error, Ru, RuBuilder, RuOptions, RuWord, RuWords};
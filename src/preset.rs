@@ -0,0 +1,228 @@
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+use crate::options::{FinalDevoicing, Preset, RuOptions};
+
+const COMMON: &[(&str, &str)] = &[
+    ("b", "б"),  ("d", "д"),  ("f", "ф"),  ("ɡ", "г"),  ("g", "г"),  ("k", "к"),  ("l", "л"),  ("r", "р"),
+    ("s", "с"),  ("t", "т"),  ("v", "в"),  ("z", "з"),  ("x", "х"),  ("ʃ", "ш"),  ("ʒ", "ж"),
+    ("t͡ʃ", "ч"), ("tʃ", "ч"), ("d͡ʒ", "дж"), ("dʒ", "дж"), ("t͡s", "ц"), ("i", "и"),  ("iː", "и"),
+];
+
+const ENGLISH: &[(&str, &str)] = &[
+    ("θ", "т"),   ("ð", "д"),   ("w", "у"),   ("ʍ", "у"),   ("ŋ", "нг"),  ("h", "х"),   ("ɹ", "р"),   ("ʔ", ""),
+    ("ɪ", "и"),   ("ʊ", "у"),   ("ɛ", "е"),   ("æ", "э"),   ("ɔ", "о"),   ("ɔː", "о"),  ("ɒ", "о"),   ("ɜ", "ё"),
+    ("ɜː", "ё"),  ("ɚ", "ер"),  ("ɝ", "ёр"),  ("ɝː", "ёр"), ("aɪ", "ай"), ("eɪ", "ей"), ("ɔɪ", "ой"), ("aʊ", "ау"),
+    ("əʊ", "оу"), ("oʊ", "оу"), ("ɪə", "иа"), ("eə", "эа"), ("ʊə", "уа"), ("ŋk", "нк"), ("ŋɡ", "нг"), ("ŋg", "нг"),
+];
+
+const GERMAN: &[(&str, &str)] = &[
+    ("ç", "хь"),  ("ʁ", "р"),   ("ʀ", "р"),   ("h", "г"),   ("ŋ", "нг"),  ("ts", "ц"),  ("pf", "пф"), ("ʔ", ""),
+    ("y", "ю"),   ("yː", "ю"),  ("ʏ", "ю"),   ("ø", "ё"),   ("øː", "ё"),  ("œ", "ё"),   ("ɪ", "и"),   ("ʊ", "у"),
+    ("ɛ", "е"),   ("ɛː", "е"),  ("ɔ", "о"),   ("ə", "е"),   ("ɐ", "ер"),  ("ɐ̯", "р"),   ("aɪ", "ай"), ("aʊ", "ау"),
+    ("ɔʏ", "ой"),
+];
+
+const GERMAN_MODERN: &[(&str, &str)] = &[("h", "х")];
+
+const FRENCH: &[(&str, &str)] = &[
+    ("ʁ", "р"),   ("h", ""),    ("ɥ", "ю"),   ("ɲ", "нь"),  ("ŋ", "нг"),  ("w", "у"),   ("e", "е"),   ("ɛ", "е"),
+    ("ɛː", "е"),  ("ə", "е"),   ("y", "ю"),   ("ø", "ё"),   ("œ", "ё"),   ("ɔ", "о"),   ("ɑ̃", "ан"),  ("ɔ̃", "он"),
+    ("ɛ̃", "ен"),  ("œ̃", "ен"),
+];
+
+const JAPANESE: &[(&str, &str)] = &[
+    ("ɕ", "с"),    ("ɕa", "ся"),  ("ɕɯ", "сю"),  ("ɕo", "сё"),  ("tɕ", "т"),   ("tɕa", "тя"),  ("tɕɯ", "тю"),  ("tɕo", "тё"),
+    ("dʑ", "дз"),  ("dʑa", "дзя"), ("dʑɯ", "дзю"), ("dʑo", "дзё"), ("ts", "ц"),   ("dz", "дз"),   ("z", "дз"),    ("ɸ", "ф"),
+    ("ç", "х"),    ("h", "х"),    ("ɾ", "р"),    ("w", "в"),    ("ɴ", "н"),    ("ŋ", "н"),     ("ɯ", "у"),     ("jɯ", "ю"),
+    ("e", "э"),    ("ː", ""),     ("kʲa", "кя"), ("kʲɯ", "кю"), ("kʲo", "кё"),  ("ɡʲa", "гя"),  ("ɡʲɯ", "гю"),
+    ("ɡʲo", "гё"), ("ɾʲa", "ря"), ("ɾʲɯ", "рю"), ("ɾʲo", "рё"), ("bʲa", "бя"), ("bʲɯ", "бю"),  ("bʲo", "бё"),
+];
+
+const MANDARIN: &[(&str, &str)] = &[
+    ("p", "б"),    ("pʰ", "п"),   ("t", "д"),    ("tʰ", "т"),   ("k", "г"),    ("kʰ", "к"),   ("ts", "цз"),  ("tsʰ", "ц"),
+    ("tʂ", "чж"),  ("tʂʰ", "ч"),  ("ʂ", "ш"),    ("ʐ", "ж"),    ("tɕ", "цз"),  ("tɕʰ", "ц"),  ("ɕ", "с"),    ("x", "х"),
+    ("ŋ", "н"),    ("w", "у"),    ("ə", "э"),    ("ɤ", "э"),    ("e", "э"),    ("y", "юй"),   ("ɻ̩", "и"),    ("ʐ̩", "и"),
+    ("ɹ̩", "ы"),    ("z̩", "ы"),    ("ɨ", "ы"),    ("ai", "ай"),  ("ei", "эй"),  ("au", "ао"),  ("ɑʊ", "ао"),  ("ou", "оу"),
+    ("oʊ", "оу"),  ("je", "е"),   ("jɛ", "е"),   ("jou", "ю"),  ("wo", "о"),   ("uo", "о"),   ("an", "ань"), ("ən", "энь"),
+    ("in", "инь"), ("yn", "юнь"), ("jɛn", "янь"), ("ʊŋ", "ун"),
+];
+
+const MANDARIN_TONES: &[(&str, &str)] = &[("˥", ""), ("˦", ""), ("˧", ""), ("˨", ""), ("˩", "")];
+
+const MANDARIN_TONE_NUMBERS: &[(&str, &str)] = &[
+    ("˥˥", "1"),   ("˥", "1"),    ("˧˥", "2"),   ("˨˩˦", "3"),  ("˨˩˩", "3"),  ("˨˩", "3"),   ("˥˩", "4"),
+];
+
+const KOREAN: &[(&str, &str)] = &[
+    ("kʰ", "кх"),  ("k͈", "кк"),   ("k̚", "к"),    ("tʰ", "тх"),  ("t͈", "тт"),   ("t̚", "т"),    ("p", "п"),    ("pʰ", "пх"),
+    ("p͈", "пп"),   ("p̚", "п"),    ("tɕ", "чж"),  ("dʑ", "дж"),  ("tɕʰ", "чх"), ("t͈ɕ", "чч"),  ("sʰ", "с"),   ("s͈", "сс"),
+    ("ɕ", "с"),    ("h", "х"),    ("ŋ", "н"),    ("ɾ", "р"),    ("l", "ль"),   ("ll", "лл"),  ("ʌ", "о"),    ("ɯ", "ы"),
+    ("ɛ", "э"),    ("ø", "ве"),   ("jʌ", "ё"),   ("jo", "ё"),   ("wa", "ва"),  ("wʌ", "во"),  ("wi", "ви"),  ("ɰi", "ый"),
+];
+
+const SPANISH: &[(&str, &str)] = &[
+    ("β", "б"),    ("ð", "д"),    ("ɣ", "г"),    ("θ", "с"),    ("ɾ", "р"),    ("ʎ", "ль"),   ("ʎa", "лья"), ("ʎe", "лье"),
+    ("ʎo", "льо"), ("ʎu", "лью"), ("ʝ", "й"),    ("ʝa", "я"),   ("ʝe", "е"),   ("ʝu", "ю"),   ("ɲ", "нь"),   ("ɲa", "нья"),
+    ("ɲe", "нье"), ("ɲo", "ньо"), ("ɲu", "нью"), ("ŋ", "н"),    ("w", "у"),    ("e", "е"),
+];
+
+const ARABIC: &[(&str, &str)] = &[
+    ("sˤ", "с"),   ("dˤ", "д"),   ("tˤ", "т"),   ("ðˤ", "з"),   ("zˤ", "з"),   ("ħ", "х"),    ("ʕ", ""),     ("ʔ", ""),
+    ("ɣ", "г"),    ("q", "к"),    ("θ", "с"),    ("ð", "з"),    ("h", "х"),    ("w", "в"),    ("ː", ""),
+];
+
+const HINDUSTANI: &[(&str, &str)] = &[
+    ("ʈ", "т"),    ("ɖ", "д"),    ("ɳ", "н"),    ("ɽ", "р"),    ("ʂ", "ш"),    ("t̪", "т"),    ("d̪", "д"),    ("pʰ", "пх"),
+    ("tʰ", "тх"),  ("t̪ʰ", "тх"),  ("ʈʰ", "тх"),  ("kʰ", "кх"),  ("tʃʰ", "чх"), ("bʱ", "бх"),  ("dʱ", "дх"),  ("d̪ʱ", "дх"),
+    ("ɖʱ", "дх"),  ("ɡʱ", "гх"),  ("dʒʱ", "джх"), ("ɦ", "х"),   ("h", "х"),    ("ʋ", "в"),    ("ŋ", "н"),    ("ɲ", "нь"),
+    ("q", "к"),    ("ɣ", "г"),    ("ə", "а"),    ("ɪ", "и"),    ("ʊ", "у"),    ("e", "е"),    ("ɛ", "е"),    ("ɔ", "о"),
+    ("ã", "ан"),   ("ẽ", "ен"),   ("ĩ", "ин"),   ("õ", "он"),   ("ũ", "ун"),   ("ː", ""),
+];
+
+const POLISH: &[(&str, &str)] = &[
+    ("ɕ", "сь"),   ("ɕa", "ся"),  ("ɕɛ", "се"),  ("ɕo", "сё"),  ("ɕɔ", "сё"),  ("ɕu", "сю"),  ("ɕi", "си"),  ("ʑ", "зь"),
+    ("ʑa", "зя"),  ("ʑɛ", "зе"),  ("ʑɔ", "зё"),  ("ʑu", "зю"),  ("ʑi", "зи"),  ("t͡ɕ", "ць"), ("tɕ", "ць"),  ("t͡ɕa", "ця"),
+    ("t͡ɕɛ", "це"), ("t͡ɕɔ", "цё"), ("t͡ɕu", "цю"), ("t͡ɕi", "ци"), ("d͡ʑ", "дзь"), ("dʑ", "дзь"), ("d͡ʑa", "дзя"), ("d͡ʑɛ", "дзе"),
+    ("d͡ʑɔ", "дзё"), ("d͡ʑu", "дзю"), ("d͡ʑi", "дзи"), ("ʂ", "ш"),   ("ʐ", "ж"),    ("t͡ʂ", "ч"),  ("tʂ", "ч"),   ("d͡ʐ", "дж"),
+    ("dʐ", "дж"),  ("ʂt͡ʂ", "щ"), ("ʂtʂ", "щ"),  ("ʂɨ", "ши"),  ("ʐɨ", "жи"),  ("t͡ʂɨ", "чи"), ("d͡ʐɨ", "джи"), ("ɲ", "нь"),
+    ("ɲa", "ня"),  ("ɲɛ", "не"),  ("ɲɔ", "нё"),  ("ɲu", "ню"),  ("w", "л"),    ("ɫ", "л"),    ("ɨ", "ы"),    ("ɛ", "е"),
+    ("ɔ", "о"),    ("ɛ̃", "ен"),   ("ɔ̃", "он"),   ("ŋ", "н"),
+];
+
+fn merged(tables: &[&[(&str, &str)]]) -> Vec<(String, String)> {
+    tables.iter().flat_map(|table| table.iter()).fold(Vec::new(), |mut overrides, (ipa, ru)| {
+        match overrides.iter_mut().find(|(known, _)| known == ipa) {
+            Some((_, known)) => *known = (*ru).to_owned(),
+            None => overrides.push(((*ipa).to_owned(), (*ru).to_owned())),
+        }
+        overrides
+    })
+}
+
+fn named(tables: &[&[(&str, &str)]]) -> RuOptions {
+    RuOptions { overrides: merged(tables), capitalize_words: true, ..RuOptions::practical() }
+}
+
+pub(crate) fn options(preset: Preset) -> RuOptions {
+    match preset {
+        Preset::English => named(&[COMMON, ENGLISH]),
+        Preset::German  => RuOptions { final_devoicing: FinalDevoicing::Restore, ..named(&[COMMON, GERMAN]) },
+        Preset::GermanModern => {
+            RuOptions { final_devoicing: FinalDevoicing::Restore, ..named(&[COMMON, GERMAN, GERMAN_MODERN]) }
+        },
+        Preset::French  => named(&[COMMON, FRENCH]),
+        Preset::Japanese => named(&[COMMON, JAPANESE]),
+        Preset::Mandarin => named(&[COMMON, MANDARIN, MANDARIN_TONES]),
+        Preset::MandarinToneNumbers => named(&[COMMON, MANDARIN, MANDARIN_TONES, MANDARIN_TONE_NUMBERS]),
+        Preset::Korean  => named(&[COMMON, KOREAN]),
+        Preset::Spanish => named(&[COMMON, SPANISH]),
+        Preset::Arabic  => named(&[COMMON, ARABIC]),
+        Preset::Hindustani => named(&[COMMON, HINDUSTANI]),
+        Preset::Polish  => named(&[COMMON, POLISH]),
+    }
+}
+
+#[cfg(test)]
+mod preset_tests {
+    use super::*;
+    use crate::ru::Ru;
+
+    fn convert(preset: Preset, ipa: &str) -> String {
+        Ru::try_from_ipa_with(ipa, RuOptions::preset(preset)).map(Ru::into_string).unwrap_or_default()
+    }
+
+    #[test]
+    fn test_merged() {
+        let overrides = merged(&[&[("a", "а"), ("b", "б")], &[("b", "в")]]);
+        assert_eq!(overrides, vec![("a".to_owned(), "а".to_owned()), ("b".to_owned(), "в".to_owned())]);
+    }
+
+    #[test]
+    fn test_english() {
+        assert_eq!(convert(Preset::English, "θɪŋk"), "Тинк");
+        assert_eq!(convert(Preset::English, "ðæt"), "Дэт");
+        assert_eq!(convert(Preset::English, "hɜːt"), "Хёт");
+        assert_eq!(convert(Preset::English, "wɪndəʊ ʃeɪkspɪə"), "Уиндоу Шейкспиа");
+    }
+
+    #[test]
+    fn test_german() {
+        assert_eq!(convert(Preset::German, "ɪç"), "Ихь");
+        assert_eq!(convert(Preset::German, "ɡøːtə"), "Гёте");
+        assert_eq!(convert(Preset::German, "mʏlɐ"), "Мюлер");
+        assert_eq!(convert(Preset::German, "hambʊʁk"), "Гамбург");
+        assert_eq!(convert(Preset::German, "ʃtaʊp"), "Штауб");
+        assert_eq!(convert(Preset::German, "liːbə"), "Либе");
+        assert_eq!(convert(Preset::GermanModern, "hambʊʁk"), "Хамбург");
+    }
+
+    #[test]
+    fn test_french() {
+        assert_eq!(convert(Preset::French, "paʁi"), "Пари");
+        assert_eq!(convert(Preset::French, "bɔ̃ʒuʁ"), "Бонжур");
+        assert_eq!(convert(Preset::French, "ʒɑ̃ vɛʁdœ̃"), "Жан Верден");
+        assert_eq!(convert(Preset::French, "nɥi"), "Нюи");
+    }
+
+    #[test]
+    fn test_japanese() {
+        assert_eq!(convert(Preset::Japanese, "sɯɕi"), "Суси");
+        assert_eq!(convert(Preset::Japanese, "tɕitɕi"), "Тити");
+        assert_eq!(convert(Preset::Japanese, "ɸɯdʑisaɴ"), "Фудзисан");
+        assert_eq!(convert(Preset::Japanese, "toːkʲoː"), "Токё");
+        assert_eq!(convert(Preset::Japanese, "ɕoːɡɯɴ"), "Сёгун");
+        assert_eq!(convert(Preset::Japanese, "keɴ"), "Кэн");
+    }
+
+    #[test]
+    fn test_mandarin() {
+        assert_eq!(convert(Preset::Mandarin, "pei˨˩tɕiŋ˥"), "Бэйцзин");
+        assert_eq!(convert(Preset::Mandarin, "ʂaŋ˥˩xai˨˩˦"), "Шанхай");
+        assert_eq!(convert(Preset::Mandarin, "mau˧˥ tsə˧˥tʊŋ˥"), "Мао Цзэдун");
+        assert_eq!(convert(Preset::MandarinToneNumbers, "ʂaŋ˥˩xai˨˩˦"), "Шан4хай3");
+    }
+
+    #[test]
+    fn test_korean() {
+        assert_eq!(convert(Preset::Korean, "sʰʌul"), "Соуль");
+        assert_eq!(convert(Preset::Korean, "pusan"), "Пусан");
+        assert_eq!(convert(Preset::Korean, "kimtɕʰi"), "Кимчхи");
+        assert_eq!(convert(Preset::Korean, "hanɡuk̚"), "Хангук");
+        assert_eq!(convert(Preset::Korean, "tɕʌndʑu"), "Чжонджу");
+    }
+
+    #[test]
+    fn test_spanish() {
+        assert_eq!(convert(Preset::Spanish, "maðɾið"), "Мадрид");
+        assert_eq!(convert(Preset::Spanish, "baɾθelona"), "Барселона");
+        assert_eq!(convert(Preset::Spanish, "malaɣa"), "Малага");
+        assert_eq!(convert(Preset::Spanish, "ʎoɾet"), "Льорет");
+        assert_eq!(convert(Preset::Spanish, "kaɲon"), "Каньон");
+    }
+
+    #[test]
+    fn test_arabic() {
+        assert_eq!(convert(Preset::Arabic, "muħammad"), "Мухаммад");
+        assert_eq!(convert(Preset::Arabic, "ʕaliː"), "Али");
+        assert_eq!(convert(Preset::Arabic, "baɣdaːd"), "Багдад");
+        assert_eq!(convert(Preset::Arabic, "qurʔaːn"), "Куран");
+        assert_eq!(convert(Preset::Arabic, "sˤaddaːm"), "Саддам");
+    }
+
+    #[test]
+    fn test_hindustani() {
+        assert_eq!(convert(Preset::Hindustani, "bʱaːrət"), "Бхарат");
+        assert_eq!(convert(Preset::Hindustani, "dɪlliː"), "Дилли");
+        assert_eq!(convert(Preset::Hindustani, "kʰənna"), "Кханна");
+        assert_eq!(convert(Preset::Hindustani, "məɦaːtmaː ɡaːnd̪ʱiː"), "Махатма Гандхи");
+    }
+
+    #[test]
+    fn test_polish() {
+        assert_eq!(convert(Preset::Polish, "bɨdɡɔʂt͡ʂ"), "Быдгощ");
+        assert_eq!(convert(Preset::Polish, "ɕrɔda"), "Сьрода");
+        assert_eq!(convert(Preset::Polish, "vawɛ̃sa"), "Валенса");
+        assert_eq!(convert(Preset::Polish, "ɡdaɲsk"), "Гданьск");
+        assert_eq!(convert(Preset::Polish, "kʂɨʂtɔf"), "Кшиштоф");
+    }
+}
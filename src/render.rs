@@ -0,0 +1,64 @@
+use core::fmt;
+
+use crate::ru::Phoneme;
+
+pub trait Render {
+    fn vowel(&mut self, output: &mut dyn fmt::Write, _phoneme: Phoneme, text: &str) -> fmt::Result {
+        output.write_str(text)
+    }
+
+    fn consonant(&mut self, output: &mut dyn fmt::Write, _phoneme: Phoneme, text: &str) -> fmt::Result {
+        output.write_str(text)
+    }
+
+    fn boundary(&mut self, output: &mut dyn fmt::Write, _phoneme: Phoneme, text: &str) -> fmt::Result {
+        output.write_str(text)
+    }
+
+    fn other(&mut self, output: &mut dyn fmt::Write, _phoneme: Phoneme, text: &str) -> fmt::Result {
+        output.write_str(text)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PlainText;
+
+impl Render for PlainText {}
+
+#[cfg(test)]
+mod render_tests {
+    use super::*;
+    use alloc::string::String;
+    use crate::ru::Ru;
+
+    struct SoftSigns;
+
+    impl Render for SoftSigns {
+        fn consonant(&mut self, output: &mut dyn fmt::Write, phoneme: Phoneme, text: &str) -> fmt::Result {
+            match (phoneme, text.strip_suffix('ь')) {
+                (Phoneme::Consonant { phoneme: _, is_palatalized: true }, Some(hard)) => write!(output, "{}<b>ь</b>", hard),
+                _ => output.write_str(text),
+            }
+        }
+
+        fn boundary(&mut self, output: &mut dyn fmt::Write, _phoneme: Phoneme, _text: &str) -> fmt::Result {
+            output.write_str("<br>")
+        }
+    }
+
+    #[test]
+    fn test_custom_render() {
+        let ru = Ru::try_from("nʲ nʲæn").unwrap();
+        let mut output = String::new();
+        ru.render_with(&mut SoftSigns, &mut output).unwrap();
+        assert_eq!(output, "н<b>ь</b><br>нян");
+    }
+
+    #[test]
+    fn test_plain_text() {
+        let ru = Ru::try_from("nʲæ nʲæn").unwrap();
+        let mut output = String::new();
+        ru.render_with(&mut PlainText, &mut output).unwrap();
+        assert_eq!(output, ru.as_str());
+    }
+}
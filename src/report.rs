@@ -0,0 +1,139 @@
+use core::fmt;
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+use crate::input::{self, Stress, Token};
+use crate::options::{ReleaseModifiers, RuOptions};
+use crate::ru::Ru;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Approximation {
+    Lookalike { symbol: char, replacement: char },
+    DroppedDiacritic { symbol: char },
+    DroppedSecondaryStress,
+    DroppedRelease { release: &'static str },
+    Vowel { ipa: String, ru: String },
+}
+
+impl fmt::Display for Approximation {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Lookalike { symbol, replacement } => write!(formatter, "{:?} read as {:?}", symbol, replacement),
+            Self::DroppedDiacritic { symbol } => write!(formatter, "diacritic {:?} dropped", symbol),
+            Self::DroppedSecondaryStress => write!(formatter, "secondary stress dropped"),
+            Self::DroppedRelease { release } => write!(formatter, "release {} dropped", release),
+            Self::Vowel { ipa, ru } => write!(formatter, "{} rendered as {}", ipa, ru),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ConversionWarning {
+    pub offset: usize,
+    pub approximation: Approximation,
+}
+
+impl fmt::Display for ConversionWarning {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} at byte {}", self.approximation, self.offset)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ConversionReport {
+    pub warnings: Vec<ConversionWarning>,
+}
+
+impl ConversionReport {
+    pub fn is_empty(&self) -> bool {
+        self.warnings.is_empty()
+    }
+}
+
+#[inline]
+fn is_approximated(vowel: ipa_sounds::Vowels) -> bool {
+    use ipa_sounds::Vowels::*;
+
+    !matches!(vowel,
+        CloseFrontUnrounded | CloseCentralUnrounded | CloseMidFrontUnrounded | CloseMidBackRounded
+        | CloseBackRounded | NearOpenFrontUrounded | OpenFrontUnrounded | OpenBackUnrounded
+    )
+}
+
+fn approximations<F>(grapheme: &str, options: &RuOptions, render_vowel: F) -> Vec<Approximation>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut approximations = grapheme.chars()
+        .filter_map(|symbol| match input::lookalikes_lookup(symbol) {
+            _ if input::is_ignored_diacritic(symbol) => Some(Approximation::DroppedDiacritic { symbol }),
+            replacement if replacement != symbol => Some(Approximation::Lookalike { symbol, replacement }),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    approximations.extend(input::tokenize(&input::normalize(grapheme), options).filter_map(|token| match token {
+        Token::Stress(Stress::Secondary) => Some(Approximation::DroppedSecondaryStress),
+        Token::Release(release) if options.release_modifiers == ReleaseModifiers::Drop => {
+            Some(Approximation::DroppedRelease { release: release.as_str() })
+        },
+        Token::Ipa(ipa_str) => ipa_sounds::Ipa::try_from(ipa_str)
+            .ok()?
+            .iter()
+            .any(|sound| matches!(*sound, ipa_sounds::Sound::Vowel { phoneme, is_long: _ } if is_approximated(phoneme)))
+            .then(|| render_vowel(ipa_str))
+            .flatten()
+            .map(|ru| Approximation::Vowel { ipa: ipa_str.to_owned(), ru }),
+        _ => None,
+    }));
+    approximations
+}
+
+pub(crate) fn report(input: &str, options: &RuOptions) -> ConversionReport {
+    ConversionReport {
+        warnings: input::graphemes(input)
+            .flat_map(|(offset, grapheme)| {
+                approximations(grapheme, options, |ipa_str| {
+                    Ru::try_from_ipa_with(ipa_str, options.clone()).ok().map(Ru::into_string)
+                })
+                    .into_iter()
+                    .map(move |approximation| ConversionWarning { offset, approximation })
+            })
+            .collect(),
+    }
+}
+
+pub(crate) fn count(input: &str, options: &RuOptions) -> usize {
+    input::graphemes(input)
+        .map(|(_, grapheme)| approximations(grapheme, options, |_| Some(String::new())).len())
+        .sum()
+}
+
+#[cfg(test)]
+mod report_tests {
+    use super::*;
+
+    #[test]
+    fn test_report() {
+        let report = report("ˌnʲʏ̪m:", &RuOptions::default());
+        assert_eq!(report.warnings, vec![
+            ConversionWarning { offset: 0, approximation: Approximation::DroppedSecondaryStress },
+            ConversionWarning { offset: 5, approximation: Approximation::DroppedDiacritic { symbol: '\u{32a}' } },
+            ConversionWarning {
+                offset: 5,
+                approximation: Approximation::Vowel { ipa: "ʏ".to_owned(), ru: "у".to_owned() },
+            },
+            ConversionWarning { offset: 9, approximation: Approximation::Lookalike { symbol: ':', replacement: 'ː' } },
+        ]);
+        assert_eq!(report.warnings[2].to_string(), "ʏ rendered as у at byte 5");
+    }
+
+    #[test]
+    fn test_clean_input() {
+        assert!(report("nʲˈæ mʲæːu", &RuOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn test_count() {
+        assert_eq!(count("ˌnʲʏ̪m:", &RuOptions::default()), 4);
+        assert_eq!(count("nʲˈæ mʲæːu", &RuOptions::default()), 0);
+    }
+}
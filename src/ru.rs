@@ -1,9 +1,36 @@
-use std::iter;
-use std::fmt;
+use core::cmp::Ordering;
+use core::iter;
+use core::mem;
+use core::ops::{Add, Bound, Range, RangeBounds};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+#[cfg(feature = "std")]
+use std::io;
+use alloc::{borrow::{Cow, ToOwned}, collections::BTreeSet, format, string::String, vec, vec::Vec};
 
-#[allow(dead_code)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum Vowels {
+use crate::audit::{AuditEvent, AuditRule, AuditSink};
+use crate::builder::RuBuilder;
+use crate::error::{Error, RenderError};
+use crate::explain::{ExplainRule, ExplainStep};
+use crate::g2p::{self, G2pSource};
+use crate::input::{self, Release, Separator, Stress, Token};
+use crate::metrics;
+use crate::notation::{arpabet, kirshenbaum, tipa, xsampa};
+use crate::options::{
+    Affricates, ClusterRules, ClusterStrategy, FinalDevoicing, Geminates, Hiatus, Hyphenation, InitialE,
+    JotAfterConsonant, JotRules, JotSpelling, Orthography, QRules, ReleaseModifiers, RuOptions, StressMarks,
+    TseVowel, VowelLength, VowelReduction, YoPolicy,
+};
+use crate::render::{PlainText, Render};
+use crate::report::{self, ConversionReport};
+use crate::rule::{self, RuRule};
+use crate::words::RuWord;
+
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
+pub enum Vowels {
     A,  E,  I,  O,  U
 }
 
@@ -36,27 +63,97 @@ fn vowels_lookup(vowel: ipa_sounds::Vowels) -> Vowels {
     }
 }
 
-#[allow(dead_code)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum Consonants {
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
+pub enum Consonants {
     P, B, F, V, K, G,
     T, D, W, X, S, Z,
-    L, M, N, R, H, C
+    L, M, N, R, H, C,
+    DX, DZ
+}
+
+impl Consonants {
+    fn voiced(self, is_palatalized: bool) -> Self {
+        use Consonants::*;
+
+        match self {
+            P                     => B,
+            F                     => V,
+            K                     => G,
+            T                     => D,
+            S                     => Z,
+            W if !is_palatalized  => X,
+            _                     => self,
+        }
+    }
+}
+
+fn voiced_letter(letter: &str) -> Option<&'static str> {
+    match letter {
+        "п" => Some("б"),
+        "ф" => Some("в"),
+        "к" => Some("г"),
+        "т" => Some("д"),
+        "с" => Some("з"),
+        "ш" => Some("ж"),
+        _   => None,
+    }
 }
 
-#[allow(dead_code)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum PalatalizedOnlyConsonants {
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
+pub enum PalatalizedOnlyConsonants {
     J, Q
 }
 
-#[allow(dead_code)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum Phoneme {
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
+pub enum Phoneme {
     Vowel { phoneme: Vowels },
     Consonant { phoneme: Consonants, is_palatalized: bool },
     PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants },
-    Probel
+    ReducedVowel,
+    MorphemeBoundary,
+    Stress,
+    SyllableBoundary,
+    Capital,
+    ClusterBreak { strategy: ClusterStrategy },
+    Override { index: usize },
+    Probel { separator: Separator }
+}
+
+impl Phoneme {
+    fn is_marker(self) -> bool {
+        matches!(self, Self::MorphemeBoundary | Self::Stress | Self::SyllableBoundary | Self::Capital)
+    }
+
+    fn substitution_cost(self, other: Self) -> usize {
+        match (self, other) {
+            _ if self == other => 0,
+            (
+                Self::Consonant { phoneme: consonant, is_palatalized },
+                Self::Consonant { phoneme: other_consonant, is_palatalized: other_is_palatalized },
+            ) if consonant.voiced(is_palatalized) == other_consonant.voiced(other_is_palatalized) => {
+                usize::from(consonant != other_consonant) + usize::from(is_palatalized != other_is_palatalized)
+            },
+            (Self::Vowel { phoneme: _ } | Self::ReducedVowel, Self::Vowel { phoneme: _ } | Self::ReducedVowel) => 1,
+            (Self::Probel { separator: _ }, Self::Probel { separator: _ }) => 0,
+            _ => INDEL_COST,
+        }
+    }
+
+    fn is_sound(self) -> bool {
+        matches!(self,
+            Self::Vowel { phoneme: _ } | Self::Consonant { phoneme: _, is_palatalized: _ }
+            | Self::PalatalizedOnlyConsonant { phoneme: _ } | Self::ReducedVowel | Self::Override { index: _ }
+        )
+    }
 }
 
 #[inline]
@@ -74,13 +171,173 @@ fn consonants_lookup(consonant: ipa_sounds::Consonants, is_palatalized: bool) ->
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-struct PhonemeSeq(Vec<Phoneme>);
+#[inline]
+fn vowels_spelling(vowel: Vowels, is_soft: bool) -> &'static str {
+    use Vowels::*;
+
+    match vowel {
+        A => if is_soft { "я" } else { "а" },
+        E => if is_soft { "е" } else { "э" },
+        I => if is_soft { "и" } else { "ы" },
+        O => if is_soft { "ё" } else { "о" },
+        U => if is_soft { "ю" } else { "у" },
+    }
+}
+
+#[inline]
+fn hardened(letter: &str) -> Option<&'static str> {
+    match letter {
+        "я" => Some("а"),
+        "е" => Some("э"),
+        "и" => Some("ы"),
+        "ё" => Some("о"),
+        "ю" => Some("у"),
+        _   => None,
+    }
+}
+
+#[inline]
+fn softened(letter: &'static str) -> &'static str {
+    match letter {
+        "а" => "я",
+        "э" => "е",
+        "ы" => "и",
+        "о" => "ё",
+        "у" => "ю",
+        _   => letter,
+    }
+}
+
+#[inline]
+fn consonants_spelling(consonant: Consonants, is_palatalized: bool, is_jer: bool, is_hushing_jer: bool) -> &'static str {
+    use Consonants::*;
+
+    match consonant {
+        P  => if is_jer { "пь" } else { "п" },
+        B  => if is_jer { "бь" } else { "б" },
+        F  => if is_jer { "фь" } else { "ф" },
+        V  => if is_jer { "вь" } else { "в" },
+        K  => if is_jer { "кь" } else { "к" },
+        G  => if is_jer { "гь" } else { "г" },
+        T  => if is_jer { "ть" } else { "т" },
+        D  => if is_jer { "дь" } else { "д" },
+        W  => if is_palatalized { "щ" } else { "ш" },
+        X  => if is_hushing_jer { "жь" } else { "ж" },
+        S  => if is_jer { "сь" } else { "с" },
+        Z  => if is_jer { "зь" } else { "з" },
+        L  => if is_jer { "ль" } else { "л" },
+        M  => if is_jer { "мь" } else { "м" },
+        N  => if is_jer { "нь" } else { "н" },
+        R  => if is_jer { "рь" } else { "р" },
+        H  => if is_jer { "хь" } else { "х" },
+        C  => if is_hushing_jer { "ць" } else { "ц" },
+        DX => if is_hushing_jer { "джь" } else { "дж" },
+        DZ => if is_jer { "дзь" } else { "дз" },
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize), archive(check_bytes))]
+pub struct PhonemeSeq(
+    Vec<Phoneme>,
+    Vec<(String, String)>,
+    #[cfg_attr(feature = "rkyv", with(rkyv::with::Skip))] Vec<Option<&'static str>>,
+);
+
+#[cfg(feature = "rkyv")]
+impl ArchivedPhonemeSeq {
+    pub fn phonemes(&self) -> &[ArchivedPhoneme] {
+        self.0.as_slice()
+    }
+}
 
 impl PhonemeSeq {
+    pub fn phonemes(&self) -> &[Phoneme] {
+        &self.0
+    }
+
+    pub fn phonemes_mut(&mut self) -> &mut Vec<Phoneme> {
+        &mut self.0
+    }
+
+    pub fn overrides(&self) -> &[(String, String)] {
+        &self.1
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn insert(&mut self, index: usize, phoneme: Phoneme) {
+        if self.is_spelled() {
+            self.2.insert(index, None);
+        }
+        self.0.insert(index, phoneme)
+    }
+
+    pub fn remove(&mut self, index: usize) -> Phoneme {
+        if self.is_spelled() {
+            self.2.remove(index);
+        }
+        self.0.remove(index)
+    }
+
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Vec<Phoneme>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = Phoneme>,
+    {
+        let is_spelled = self.is_spelled();
+        let len = self.0.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let removed = self.0.splice(range, replace_with).collect::<Vec<_>>();
+        if is_spelled {
+            let inserted = self.0.len() + removed.len() - len;
+            self.2.splice(start..start + removed.len(), iter::repeat(None).take(inserted));
+        }
+        removed
+    }
+
+    pub fn retain<F: FnMut(&Phoneme) -> bool>(&mut self, mut keep: F) {
+        let kept = self.0.iter().map(&mut keep).collect::<Vec<_>>();
+        if self.is_spelled() {
+            self.2 = retained(mem::take(&mut self.2), &kept);
+        }
+        self.0 = retained(mem::take(&mut self.0), &kept);
+    }
+
+    pub fn spelling(&self, i: usize) -> Option<&'static str> {
+        self.2.get(i).copied().flatten().filter(|_| self.is_spelled())
+    }
+
+    pub fn spell(&mut self, i: usize, letter: &'static str) {
+        if !self.is_spelled() {
+            self.2 = vec![None; self.0.len()];
+        }
+        if let Some(spelling) = self.2.get_mut(i) {
+            *spelling = Some(letter);
+        }
+    }
+
+    fn is_spelled(&self) -> bool {
+        !self.2.is_empty() && self.2.len() == self.0.len()
+    }
+
     fn new(ipa: ipa_sounds::Ipa) -> Self {
+        Self::from_sounds(ipa.iter())
+    }
+
+    fn from_sounds<'a, I: IntoIterator<Item = &'a ipa_sounds::Sound>>(sounds: I) -> Self {
         Self (
-            ipa.iter()
+            sounds.into_iter()
             .flat_map(|sound| {        
                 let (phoneme, is_long) = match *sound {
                     ipa_sounds::Sound::Vowel { phoneme, is_long } => (
@@ -91,252 +348,2135 @@ impl PhonemeSeq {
                         consonants_lookup(phoneme, is_palatalized),
                         is_long
                     ),
-                    ipa_sounds::Sound::Space => (Phoneme::Probel, false)
+                    ipa_sounds::Sound::Space => (Phoneme::Probel { separator: Separator::Space }, false)
                 };
                 iter::repeat(phoneme).take(is_long as usize + 1)
             })
-            .collect::<Vec<Phoneme>>()
+            .collect::<Vec<Phoneme>>(),
+            Vec::new(),
+            Vec::new()
         )
     }
-}
 
-#[deny(unused_must_use)]
-impl fmt::Display for PhonemeSeq {
-    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        (0..self.0.len()).try_for_each(|i| {
-            let is_prev_palatalized = match i {
-                0 => false,
-                _ => match self.0[i - 1] {
-                    Phoneme::Vowel { phoneme: _ } => false,
-                    Phoneme::Consonant { phoneme: _, is_palatalized } => is_palatalized,
-                    Phoneme::PalatalizedOnlyConsonant { phoneme: _ } => true,
-                    Phoneme::Probel => false
+    fn parse(input: &str, options: &RuOptions, rules: &[&dyn RuRule]) -> Result<Self, Error> {
+        Self::tokenized(input, options).map(|seq| seq.converted(options, rules))
+    }
+
+    pub(crate) fn tokenized(input: &str, options: &RuOptions) -> Result<Self, Error> {
+        Self::parse_tokens(input, options).map_err(|error| {
+            Self::locate_error(input, options).unwrap_or_else(|| Error::ParseError {
+                input: input.to_owned(),
+                reason: format!("{:?}", error),
+            })
+        })
+    }
+
+    pub(crate) fn converted(mut self, options: &RuOptions, rules: &[&dyn RuRule]) -> Self {
+        rule::builtin(options).into_iter().chain(rules.iter().copied()).for_each(|rule| rule.apply(&mut self));
+        self
+    }
+
+    fn is_valid_grapheme(grapheme: &str, options: &RuOptions) -> bool {
+        input::tokenize(&input::normalize(grapheme), options).all(|token| match token {
+            Token::Ipa(ipa_str) => ipa_sounds::Ipa::try_from(ipa_str).is_ok(),
+            _ => true,
+        })
+    }
+
+    fn locate_error(input: &str, options: &RuOptions) -> Option<Error> {
+        input::graphemes(input)
+            .find(|(_, grapheme)| !Self::is_valid_grapheme(grapheme, options))
+            .map(|(offset, grapheme)| {
+                let char_offset = input[..offset].chars().count();
+                match grapheme.chars().next().filter(|base| Self::is_valid_grapheme(base.encode_utf8(&mut [0; 4]), options)) {
+                    Some(_) => Error::UnsupportedCombination { offset, char_offset, symbols: grapheme.to_owned() },
+                    None => Error::UnsupportedSymbol { offset, char_offset, symbol: grapheme.to_owned() },
                 }
-            };
-            let is_vowel_next = if i == self.0.len() - 1 {
-                false
-            } else {
-                match self.0[i + 1] {
-                    Phoneme::Vowel { phoneme: _ } => true,
-                    Phoneme::Consonant { phoneme: _, is_palatalized: _ } => false,
-                    Phoneme::PalatalizedOnlyConsonant { phoneme: _ } => false,
-                    Phoneme::Probel => false
+            })
+    }
+
+    fn parse_tokens(input: &str, options: &RuOptions) -> Result<Self, ipa_sounds::Error> {
+        let mut is_stress_pending = false;
+        let seq = Self(Vec::new(), options.overrides.clone(), Vec::new());
+        input::tokenize(&input::normalize(input), options).try_fold(seq, |mut seq, token| {
+            match token {
+                Token::Ipa(ipa_str) => {
+                    let start = seq.0.len();
+                    seq.0.extend(Self::new(ipa_sounds::Ipa::try_from(ipa_str)?).0);
+                    let stressed = seq.0[start..].iter().position(|phoneme| matches!(phoneme, Phoneme::Vowel { phoneme: _ }));
+                    if let Some(vowel) = stressed.filter(|_| is_stress_pending) {
+                        seq.0.insert(start + vowel + 1, Phoneme::Stress);
+                        is_stress_pending = false;
+                    }
+                },
+                Token::Stress(Stress::Primary) => is_stress_pending = true,
+                Token::Stress(Stress::Secondary) => {},
+                Token::Separator(separator) => seq.0.push(Phoneme::Probel { separator }),
+                Token::MorphemeBoundary => seq.0.push(Phoneme::MorphemeBoundary),
+                Token::SyllableBoundary => seq.0.push(Phoneme::SyllableBoundary),
+                Token::Capital => seq.0.push(Phoneme::Capital),
+                Token::Override { ipa: _, index } => seq.0.push(Phoneme::Override { index }),
+                Token::Release(Release::Schwa) if options.release_modifiers == ReleaseModifiers::ReducedVowel => {
+                    seq.0.push(Phoneme::ReducedVowel)
+                },
+                Token::Release(_) => {},
+            }
+            Ok(seq)
+        })
+    }
+
+    pub(crate) fn syllabify(mut self, hyphenation: Hyphenation) -> Self {
+        if hyphenation != Hyphenation::Computed {
+            return self;
+        }
+        let mut boundaries = Vec::new();
+        let mut onset = Vec::new();
+        let mut is_after_vowel = false;
+        let mut is_marked = false;
+        self.0.iter().enumerate().for_each(|(i, phoneme)| match phoneme {
+            Phoneme::Vowel { phoneme: _ } => {
+                let is_jot_last = onset.last().map(|&last| self.0[last])
+                    == Some(Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J });
+                let start = match onset.len() {
+                    0 => None,
+                    len if len > 1 && is_jot_last => Some(onset[len - 2]),
+                    len => Some(onset[len - 1]),
+                };
+                boundaries.extend(start.filter(|_| is_after_vowel && !is_marked));
+                is_after_vowel = true;
+                is_marked = false;
+                onset.clear();
+            },
+            Phoneme::Consonant { phoneme: _, is_palatalized: _ } | Phoneme::PalatalizedOnlyConsonant { phoneme: _ } => {
+                onset.push(i)
+            },
+            Phoneme::SyllableBoundary => is_marked = true,
+            Phoneme::Probel { separator: _ } => {
+                is_after_vowel = false;
+                onset.clear();
+            },
+            _ => {},
+        });
+        boundaries.into_iter().rev().for_each(|i| self.0.insert(i, Phoneme::SyllableBoundary));
+        self
+    }
+
+    fn join(mut self, other: Self) -> Self {
+        let is_boundary = |phoneme: Option<&Phoneme>| matches!(phoneme, None | Some(Phoneme::Probel { separator: _ }));
+        if !is_boundary(self.0.last()) && !is_boundary(other.0.first()) {
+            self.0.push(Phoneme::Probel { separator: Separator::Space });
+        }
+        let mut spellings = mem::take(&mut self.2);
+        spellings.resize(self.0.len(), None);
+        spellings.extend(if other.is_spelled() { other.2 } else { vec![None; other.0.len()] });
+        if spellings.iter().any(Option::is_some) {
+            self.2 = spellings;
+        }
+        let shift = if self.1 == other.1 { 0 } else { self.1.len() };
+        self.0.extend(other.0.into_iter().map(|phoneme| match phoneme {
+            Phoneme::Override { index } => Phoneme::Override { index: index + shift },
+            _ => phoneme,
+        }));
+        if self.1 != other.1 {
+            self.1.extend(other.1);
+        }
+        self
+    }
+
+    pub(crate) fn decompose_affricates(self, affricates: Affricates) -> Self {
+        if affricates == Affricates::Digraph {
+            return self;
+        }
+        let mut prev = None;
+        Self (
+            self.0.into_iter().fold(Vec::new(), |mut decomposed, phoneme| {
+                let fricative = match phoneme {
+                    Phoneme::Consonant { phoneme: Consonants::DX, is_palatalized } => {
+                        Some(Phoneme::Consonant { phoneme: Consonants::X, is_palatalized })
+                    },
+                    Phoneme::Consonant { phoneme: Consonants::DZ, is_palatalized } => {
+                        Some(Phoneme::Consonant { phoneme: Consonants::Z, is_palatalized })
+                    },
+                    _ => None,
+                };
+                match fricative {
+                    Some(fricative) if prev == Some(phoneme) => {
+                        let stop = decomposed.iter().rposition(|phoneme| *phoneme == fricative).map_or(0, |stop| stop + 1);
+                        decomposed.insert(stop, fricative);
+                    },
+                    Some(fricative) => decomposed.extend([
+                        Phoneme::Consonant { phoneme: Consonants::D, is_palatalized: false },
+                        fricative,
+                    ]),
+                    None => decomposed.push(phoneme),
                 }
-            };
-            let is_consonant_prev = match i {
-                0 => false,
-                _ => match self.0[i - 1] {
-                    Phoneme::Vowel { phoneme: _ } => false,
-                    Phoneme::Consonant { phoneme: _, is_palatalized: _ } => true,
-                    Phoneme::PalatalizedOnlyConsonant { phoneme: _ } => true,
-                    Phoneme::Probel => false
+                if !phoneme.is_marker() {
+                    prev = Some(phoneme);
                 }
-            };
-            let is_q_or_wj_prev = match i {
-                0 => false,
-                _ => match self.0[i - 1] {
-                    Phoneme::Vowel { phoneme: _ } => false,
-                    Phoneme::Consonant { phoneme, is_palatalized } => match phoneme {
-                        Consonants::W => is_palatalized,
-                        _ => false
+                decomposed
+            }),
+            self.1,
+            Vec::new()
+        )
+    }
+
+    pub(crate) fn repair_clusters(self, rules: &ClusterRules) -> Self {
+        if rules.strategy == ClusterStrategy::Keep || rules.max_len == 0 {
+            return self;
+        }
+        let mut run = 0;
+        Self (
+            self.0.into_iter().fold(Vec::new(), |mut repaired, phoneme| {
+                match phoneme {
+                    Phoneme::Consonant { phoneme: _, is_palatalized: _ } | Phoneme::PalatalizedOnlyConsonant { phoneme: _ } => {
+                        if run == rules.max_len {
+                            repaired.push(Phoneme::ClusterBreak { strategy: rules.strategy });
+                            run = 0;
+                        }
+                        run += 1;
                     },
-                    Phoneme::PalatalizedOnlyConsonant { phoneme } => matches!(phoneme, PalatalizedOnlyConsonants::Q),
-                    Phoneme::Probel => false
+                    Phoneme::MorphemeBoundary | Phoneme::Stress | Phoneme::SyllableBoundary | Phoneme::Capital => {},
+                    _ => run = 0,
                 }
-            };
-            write!(formatter, "{}", match self.0[i] {
-                Phoneme::Vowel { phoneme } => {
-                    let is_vowel_palatalizing = is_prev_palatalized && !is_q_or_wj_prev;
-                    match phoneme {
-                        Vowels::A => if is_vowel_palatalizing { "я" } else { "а" },
-                        Vowels::E => if is_vowel_palatalizing { "е" } else { "э" },
-                        Vowels::I => if is_vowel_palatalizing { "и" } else { "ы" },
-                        Vowels::O => if is_vowel_palatalizing { "ё" } else { "о" },
-                        Vowels::U => if is_vowel_palatalizing { "ю" } else { "у" },
-                    }
+                repaired.push(phoneme);
+                repaired
+            }),
+            self.1,
+            Vec::new()
+        )
+    }
+
+    pub(crate) fn spell_geminates(&mut self, geminates: Geminates) {
+        if geminates == Geminates::Single {
+            self.respell(|seq, i, _| seq.is_geminate_first(i).then_some(""));
+        }
+    }
+
+    pub(crate) fn restore_voicing(&mut self, final_devoicing: FinalDevoicing) {
+        if final_devoicing != FinalDevoicing::Restore {
+            return;
+        }
+        let finals = (0..self.0.len())
+            .filter(|&i| {
+                matches!(self.0[i], Phoneme::Consonant { phoneme: _, is_palatalized: _ } | Phoneme::Override { index: _ })
+                    && self.is_word_final(i)
+            })
+            .collect::<Vec<_>>();
+        finals.into_iter().for_each(|end| {
+            let start = self.0[..end].iter().rposition(|phoneme| *phoneme != self.0[end]).map_or(0, |start| start + 1);
+            (start..=end).for_each(|i| match self.0[i] {
+                Phoneme::Consonant { phoneme, is_palatalized } => {
+                    self.0[i] = Phoneme::Consonant { phoneme: phoneme.voiced(is_palatalized), is_palatalized };
                 },
-                Phoneme::Consonant {phoneme, is_palatalized } => {
-                    let is_jer = is_palatalized && !is_vowel_next;
-                    match phoneme {
-                        Consonants::P => if is_jer { "пь" } else { "п" },
-                        Consonants::B => if is_jer { "бь" } else { "б" },
-                        Consonants::F => if is_jer { "фь" } else { "ф" },
-                        Consonants::V => if is_jer { "вь" } else { "в" },
-                        Consonants::K => if is_jer { "кь" } else { "к" },
-                        Consonants::G => if is_jer { "гь" } else { "г" },
-                        Consonants::T => if is_jer { "ть" } else { "т" },
-                        Consonants::D => if is_jer { "дь" } else { "д" },
-                        Consonants::W => if is_palatalized { "щ" } else { "ш" },
-                        Consonants::X => if is_jer { "жь" } else { "ж" },
-                        Consonants::S => if is_jer { "сь" } else { "с" },
-                        Consonants::Z => if is_jer { "зь" } else { "з" },
-                        Consonants::L => if is_jer { "ль" } else { "л" },
-                        Consonants::M => if is_jer { "мь" } else { "м" },
-                        Consonants::N => if is_jer { "нь" } else { "н" },
-                        Consonants::R => if is_jer { "рь" } else { "р" },
-                        Consonants::H => if is_jer { "хь" } else { "х" },
-                        Consonants::C => if is_jer { "сь" } else { "с" },
+                Phoneme::Override { index } => {
+                    if let Some(letter) = self.1.get(index).and_then(|(_, ru)| voiced_letter(ru)) {
+                        self.spell(i, letter);
                     }
                 },
-                Phoneme::PalatalizedOnlyConsonant { phoneme } => match phoneme {
-                    PalatalizedOnlyConsonants::J => if is_vowel_next && is_consonant_prev {
-                        "ъ"
-                    } else if !is_vowel_next {
-                        "й"
-                    } else {
-                        ""
-                    },
-                    PalatalizedOnlyConsonants::Q => "ч"
-                },
-                Phoneme::Probel => " "
-            })
-        })
+                _ => {},
+            });
+        });
     }
-}
 
-#[derive(Clone)]
-pub struct Ru(PhonemeSeq);
+    pub(crate) fn spell_vowel_length(&mut self, vowel_length: VowelLength) {
+        if let Some(tail) = vowel_length.tail() {
+            self.respell(|seq, i, _| seq.is_long_vowel_tail(i).then_some(tail));
+        }
+    }
 
-impl fmt::Display for Ru {
-    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(formatter)
+    pub(crate) fn spell_jot_after_consonant(&mut self, jot_after_consonant: JotAfterConsonant) {
+        self.respell(|seq, i, letter| {
+            let is_boundary = seq.is_morpheme_boundary_prev(i);
+            match jot_after_consonant {
+                _ if seq.0[i] != JOT || letter != "ъ" => None,
+                JotAfterConsonant::HardSign           => None,
+                JotAfterConsonant::MorphemeBoundary   => (!is_boundary).then_some("ь"),
+                JotAfterConsonant::SoftSign           => Some("ь"),
+                JotAfterConsonant::Auto               => (!is_boundary && !seq.is_prefix_prev(i)).then_some("ь"),
+            }
+        });
     }
-}
 
-impl From<ipa_sounds::Ipa> for Ru {
-    fn from(ipa: ipa_sounds::Ipa) -> Self {
-        Self(PhonemeSeq::new(ipa))
+    pub(crate) fn spell_jots(&mut self, rules: &JotRules) {
+        self.respell(|seq, i, letter| match letter {
+            _ if seq.0[i] != JOT            => None,
+            "" if seq.is_onset_jot(i)       => Some(rules.onset.as_str()),
+            "й" if seq.is_consonant_prev(i) => Some(rules.between_consonants.as_str()),
+            "й" if seq.is_vowel_prev(i)     => Some(rules.coda.as_str()),
+            "й"                             => Some(rules.word_initial.as_str()),
+            _                               => None,
+        });
+        if rules.onset != JotSpelling::Omit {
+            self.respell(|seq, i, letter| {
+                let is_onset_prev = seq.prev_index(i).is_some_and(|prev| seq.is_onset_jot(prev));
+                hardened(letter).filter(|_| seq.is_vowel(i) && is_onset_prev)
+            });
+        }
     }
-}
 
-impl TryFrom<&str> for Ru {
-    type Error = ipa_sounds::Error;
+    pub(crate) fn spell_after_hushing(&mut self, orthography: Orthography) {
+        if orthography != Orthography::Standard {
+            return;
+        }
+        self.respell(|seq, i, letter| match letter {
+            _ if !seq.is_vowel(i)                                   => None,
+            "э" if seq.is_q_or_wj_prev(i)                           => Some("е"),
+            "о" if seq.is_q_or_wj_prev(i)                           => Some("ё"),
+            "ы" if seq.is_q_or_wj_prev(i) || seq.is_hushing_prev(i) => Some("и"),
+            _                                                       => None,
+        });
+    }
 
-    fn try_from(ipa_str: &str) -> Result<Self, Self::Error> {
-        ipa_sounds::Ipa::try_from(ipa_str).map(Self::from)
+    pub(crate) fn spell_after_tse(&mut self, tse_vowel: TseVowel) {
+        if tse_vowel == TseVowel::I {
+            self.respell(|seq, i, letter| (seq.is_vowel(i) && letter == "ы" && seq.is_tse_prev(i)).then_some("и"));
+        }
     }
-}
 
-impl TryFrom<String> for Ru {
-    type Error = ipa_sounds::Error;
+    pub(crate) fn spell_jers(&mut self, is_hushing: bool) {
+        self.respell(|seq, i, letter| match seq.0[i] {
+            Phoneme::Consonant { phoneme, is_palatalized } if seq.is_jer(i) => {
+                let plain = consonants_spelling(phoneme, is_palatalized, false, false);
+                let jer = consonants_spelling(phoneme, is_palatalized, !is_hushing, is_hushing);
+                (letter == plain && jer != plain).then_some(jer)
+            },
+            _ => None,
+        });
+    }
 
-    fn try_from(ipa_string: String) -> Result<Self, Self::Error> {
-        ipa_sounds::Ipa::try_from(ipa_string).map(Self::from)
+    pub(crate) fn spell_yo(&mut self, yo_policy: YoPolicy) {
+        if yo_policy == YoPolicy::Ye {
+            self.respell(|seq, i, letter| (seq.is_vowel(i) && letter == "ё").then_some("е"));
+        }
+    }
+
+    pub(crate) fn spell_initial_e(&mut self, initial_e: InitialE) {
+        if initial_e == InitialE::Ye {
+            self.respell(|seq, i, letter| (seq.is_vowel(i) && letter == "э" && seq.is_word_initial(i)).then_some("е"));
+        }
+    }
+
+    pub(crate) fn spell_q(&mut self, rules: &QRules) {
+        self.respell(|seq, i, letter| {
+            let is_q = seq.0[i] == Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::Q };
+            let spelling = if seq.is_consonant_next(i) { rules.before_consonant } else { rules.word_final };
+            (is_q && letter == "ч" && !seq.is_vowel_next(i)).then_some(spelling.as_str())
+        });
+    }
+
+    pub(crate) fn reduce_vowels(&mut self, vowel_reduction: VowelReduction) {
+        if vowel_reduction == VowelReduction::Akanye {
+            self.respell(|seq, i, letter| match letter {
+                _ if !seq.is_vowel(i) || !seq.is_unstressed(i) => None,
+                "о"                                            => Some("а"),
+                "е" | "ё"                                      => Some("и"),
+                _                                              => None,
+            });
+        }
     }
 }
 
-#[cfg(test)]
-mod ru_phoneme_seq_fmt_tests {
-    use super::*;
+fn retained<T>(items: Vec<T>, kept: &[bool]) -> Vec<T> {
+    items.into_iter().zip(kept).filter(|(_, is_kept)| **is_kept).map(|(item, _)| item).collect()
+}
 
-    #[test]
-    fn test_na() {
-        assert_eq!(format!("{}", PhonemeSeq(vec![
-            Phoneme::Consonant { phoneme: Consonants::N, is_palatalized: true },
-            Phoneme::Vowel { phoneme: Vowels::A },
-        ])), "ня");
+const REPLACEMENT: &str = "\u{FFFD}";
+
+const JOT: Phoneme = Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J };
+
+const SOURCE_LOOKAHEAD: usize = 4;
+
+const INDEL_COST: usize = 2;
+
+const ALTERNATIVES: [(&str, &[&str]); 4] = [
+    ("θ", &["т", "с", "ф"]),
+    ("w", &["в", "у"]),
+    ("h", &["х", "г"]),
+    ("ə", &["е", "а", "о", "э"]),
+];
+
+impl PhonemeSeq {
+    fn prev_index(&self, i: usize) -> Option<usize> {
+        self.0.get(..i)?.iter().rposition(|phoneme| !phoneme.is_marker())
     }
 
-    #[test]
-    fn test_jer() {
-        assert_eq!(format!("{}", PhonemeSeq(vec![
-            Phoneme::Consonant { phoneme: Consonants::P, is_palatalized: false },
-            Phoneme::Vowel { phoneme: Vowels::O },
-            Phoneme::Consonant { phoneme: Consonants::D, is_palatalized: false },
-            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J },
-            Phoneme::Vowel { phoneme: Vowels::E },
-            Phoneme::Consonant { phoneme: Consonants::Z, is_palatalized: false },
-            Phoneme::Consonant { phoneme: Consonants::D, is_palatalized: false },
-        ])), "подъезд");
+    fn prev(&self, i: usize) -> Option<Phoneme> {
+        self.prev_index(i).and_then(|prev| self.0.get(prev).copied())
     }
 
-    #[test]
-    fn test_huj() {
-        assert_eq!(format!("{}", PhonemeSeq(vec![
-            Phoneme::Consonant { phoneme: Consonants::H, is_palatalized: false },
-            Phoneme::Vowel { phoneme: Vowels::U },
-            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J },
-        ])), "хуй");
+    fn next_index(&self, i: usize) -> Option<usize> {
+        self.0.get(i + 1..)?.iter().position(|phoneme| !phoneme.is_marker()).map(|next| i + 1 + next)
     }
 
-    #[test]
-    fn test_intervokalnij_jot() {
-        assert_eq!(format!("{}", PhonemeSeq(vec![
-            Phoneme::Vowel { phoneme: Vowels::A },
-            Phoneme::Consonant { phoneme: Consonants::H, is_palatalized: false },
-            Phoneme::Vowel { phoneme: Vowels::U },
-            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J },
-            Phoneme::Vowel { phoneme: Vowels::E },
-            Phoneme::Consonant { phoneme: Consonants::T, is_palatalized: true },
-        ])), "ахуеть");
+    fn next(&self, i: usize) -> Option<Phoneme> {
+        self.next_index(i).and_then(|next| self.0.get(next).copied())
     }
 
-    #[test]
-    fn test_naqalnij_jot() {
-        assert_eq!(format!("{}", PhonemeSeq(vec![
-            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J },
-            Phoneme::Vowel { phoneme: Vowels::E },
-            Phoneme::Consonant { phoneme: Consonants::B, is_palatalized: false },
-            Phoneme::Vowel { phoneme: Vowels::A },
-            Phoneme::Consonant { phoneme: Consonants::T, is_palatalized: true },
-        ])), "ебать");
+    fn is_morpheme_boundary_prev(&self, i: usize) -> bool {
+        i.checked_sub(1).and_then(|prev| self.0.get(prev)) == Some(&Phoneme::MorphemeBoundary)
     }
 
-    #[test]
-    fn test_wuwa() {
-        assert_eq!(format!("{}", PhonemeSeq(vec![
-            Phoneme::Consonant { phoneme: Consonants::W, is_palatalized: true },
-            Phoneme::Vowel { phoneme: Vowels::U },
-            Phoneme::Consonant { phoneme: Consonants::W, is_palatalized: false },
-            Phoneme::Vowel { phoneme: Vowels::A },
-        ])), "щуша");
+    fn is_consonant_prev(&self, i: usize) -> bool {
+        match self.prev(i) {
+            None => false,
+            Some(Phoneme::Vowel { phoneme: _ }) => false,
+            Some(Phoneme::Consonant { phoneme: _, is_palatalized: _ }) => true,
+            Some(Phoneme::PalatalizedOnlyConsonant { phoneme: _ }) => true,
+            Some(Phoneme::ReducedVowel) => false,
+            Some(Phoneme::MorphemeBoundary) => false,
+            Some(Phoneme::Stress) => false,
+            Some(Phoneme::SyllableBoundary) => false,
+            Some(Phoneme::Capital) => false,
+            Some(Phoneme::Override { index: _ }) => false,
+            Some(Phoneme::ClusterBreak { strategy: _ }) => false,
+            Some(Phoneme::Probel { separator: _ }) => false
+        }
     }
 
-    #[test]
-    fn test_qakra() {
-        assert_eq!(format!("{}", PhonemeSeq(vec![
-            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::Q },
-            Phoneme::Vowel { phoneme: Vowels::A },
-            Phoneme::Consonant { phoneme: Consonants::K, is_palatalized: false },
-            Phoneme::Consonant { phoneme: Consonants::R, is_palatalized: false },
-            Phoneme::Vowel { phoneme: Vowels::A },
-        ])), "чакра");
+    fn is_consonant_next(&self, i: usize) -> bool {
+        match self.next(i) {
+            None => false,
+            Some(Phoneme::Vowel { phoneme: _ }) => false,
+            Some(Phoneme::Consonant { phoneme: _, is_palatalized: _ }) => true,
+            Some(Phoneme::PalatalizedOnlyConsonant { phoneme: _ }) => true,
+            Some(Phoneme::ReducedVowel) => false,
+            Some(Phoneme::MorphemeBoundary) => false,
+            Some(Phoneme::Stress) => false,
+            Some(Phoneme::SyllableBoundary) => false,
+            Some(Phoneme::Capital) => false,
+            Some(Phoneme::Override { index: _ }) => false,
+            Some(Phoneme::ClusterBreak { strategy: _ }) => false,
+            Some(Phoneme::Probel { separator: _ }) => false
+        }
     }
-}
 
-#[cfg(test)]
-mod ru_integration_tests {
-    use super::*;
+    fn is_vowel_prev(&self, i: usize) -> bool {
+        match self.prev(i) {
+            None => false,
+            Some(Phoneme::Vowel { phoneme: _ }) => true,
+            Some(Phoneme::Consonant { phoneme: _, is_palatalized: _ }) => false,
+            Some(Phoneme::PalatalizedOnlyConsonant { phoneme: _ }) => false,
+            Some(Phoneme::ReducedVowel) => true,
+            Some(Phoneme::MorphemeBoundary) => false,
+            Some(Phoneme::Stress) => false,
+            Some(Phoneme::SyllableBoundary) => false,
+            Some(Phoneme::Capital) => false,
+            Some(Phoneme::Override { index: _ }) => false,
+            Some(Phoneme::ClusterBreak { strategy }) => strategy.is_epenthetic(),
+            Some(Phoneme::Probel { separator: _ }) => false
+        }
+    }
 
-    #[test]
-    fn test_na() {
-        assert_eq!(
-            Ru::try_from("nʲæ").map(|ru| format!("{}", ru)),
-            Ok("ня".to_owned())
-        );
+    fn is_palatalized_prev(&self, i: usize) -> bool {
+        match self.prev(i) {
+            None => false,
+            Some(Phoneme::Vowel { phoneme: _ }) => false,
+            Some(Phoneme::Consonant { phoneme: _, is_palatalized }) => is_palatalized,
+            Some(Phoneme::PalatalizedOnlyConsonant { phoneme: _ }) => true,
+            Some(Phoneme::ReducedVowel) => false,
+            Some(Phoneme::MorphemeBoundary) => false,
+            Some(Phoneme::Stress) => false,
+            Some(Phoneme::SyllableBoundary) => false,
+            Some(Phoneme::Capital) => false,
+            Some(Phoneme::Override { index: _ }) => false,
+            Some(Phoneme::ClusterBreak { strategy: _ }) => false,
+            Some(Phoneme::Probel { separator: _ }) => false
+        }
     }
 
-    #[test]
-    fn test_na_nan() {
-        assert_eq!(
-            Ru::try_from("nʲæ nʲæn").map(|ru| format!("{}", ru)),
-            Ok("ня нян".to_owned())
-        );
+    fn is_vowel_next(&self, i: usize) -> bool {
+        match self.next(i) {
+            None => false,
+            Some(Phoneme::Vowel { phoneme: _ }) => true,
+            Some(Phoneme::Consonant { phoneme: _, is_palatalized: _ }) => false,
+            Some(Phoneme::PalatalizedOnlyConsonant { phoneme: _ }) => false,
+            Some(Phoneme::ReducedVowel) => true,
+            Some(Phoneme::MorphemeBoundary) => false,
+            Some(Phoneme::Stress) => false,
+            Some(Phoneme::SyllableBoundary) => false,
+            Some(Phoneme::Capital) => false,
+            Some(Phoneme::Override { index: _ }) => false,
+            Some(Phoneme::ClusterBreak { strategy }) => strategy.is_epenthetic(),
+            Some(Phoneme::Probel { separator: _ }) => false
+        }
     }
 
-    #[test]
-    fn test_maau() {
-        assert_eq!(
-            Ru::try_from("mʲæːu").map(|ru| format!("{}", ru)),
-            Ok("мяау".to_owned())
-        );
+    fn is_q_or_wj_prev(&self, i: usize) -> bool {
+        match self.prev(i) {
+            None => false,
+            Some(Phoneme::Vowel { phoneme: _ }) => false,
+            Some(Phoneme::Consonant { phoneme: Consonants::W, is_palatalized }) => is_palatalized,
+            Some(Phoneme::Consonant { phoneme: _, is_palatalized: _ }) => false,
+            Some(Phoneme::PalatalizedOnlyConsonant { phoneme }) => matches!(phoneme, PalatalizedOnlyConsonants::Q),
+            Some(Phoneme::ReducedVowel) => false,
+            Some(Phoneme::MorphemeBoundary) => false,
+            Some(Phoneme::Stress) => false,
+            Some(Phoneme::SyllableBoundary) => false,
+            Some(Phoneme::Capital) => false,
+            Some(Phoneme::Override { index: _ }) => false,
+            Some(Phoneme::ClusterBreak { strategy: _ }) => false,
+            Some(Phoneme::Probel { separator: _ }) => false
+        }
     }
 
-    #[test]
-    fn test_mmaau() {
-        assert_eq!(
-            Ru::try_from("mʲːæːu").map(|ru| format!("{}", ru)),
-            Ok("мьмяау".to_owned())
+    fn is_tse_prev(&self, i: usize) -> bool {
+        matches!(self.prev(i), Some(Phoneme::Consonant { phoneme: Consonants::C, is_palatalized: false }))
+    }
+
+    fn is_hushing_prev(&self, i: usize) -> bool {
+        matches!(
+            self.prev(i),
+            Some(Phoneme::Consonant { phoneme: Consonants::X | Consonants::W | Consonants::DX, is_palatalized: _ })
+        )
+    }
+
+    fn is_word_initial(&self, i: usize) -> bool {
+        matches!(self.prev(i), None | Some(Phoneme::Probel { separator: _ }))
+    }
+
+    fn is_word_final(&self, i: usize) -> bool {
+        matches!(self.next(i), None | Some(Phoneme::Probel { separator: _ }))
+    }
+
+    fn is_vowel(&self, i: usize) -> bool {
+        matches!(self.0.get(i), Some(Phoneme::Vowel { phoneme: _ }))
+    }
+
+    fn is_onset_jot(&self, i: usize) -> bool {
+        self.0.get(i) == Some(&JOT) && self.is_vowel_next(i) && !self.is_consonant_prev(i)
+    }
+
+    fn is_prefix_prev(&self, i: usize) -> bool {
+        let Some(prev) = self.prev_index(i) else {
+            return false;
+        };
+        let start = self.0[..prev]
+            .iter()
+            .rposition(|phoneme| matches!(phoneme, Phoneme::Probel { separator: _ }))
+            .map_or(0, |end| end + 1);
+        let onset = self.0[start..prev].iter().filter(|phoneme| !phoneme.is_marker()).collect::<Vec<_>>();
+        let vowels = onset.iter().filter(|phoneme| matches!(phoneme, Phoneme::Vowel { phoneme: _ })).count();
+        let is_letter_prefix = onset.is_empty()
+            && matches!(self.0[prev], Phoneme::Consonant { phoneme: Consonants::S | Consonants::V, is_palatalized: false });
+        let is_syllable_prefix = vowels == 1
+            && matches!(onset.last(), Some(Phoneme::Vowel { phoneme: _ }))
+            && matches!(self.0[prev], Phoneme::Consonant {
+                phoneme: Consonants::B | Consonants::D | Consonants::T | Consonants::Z | Consonants::S,
+                is_palatalized: false
+            });
+        is_letter_prefix || is_syllable_prefix
+    }
+
+    fn is_long_vowel_tail(&self, i: usize) -> bool {
+        let current = self.0.get(i);
+        matches!(current, Some(Phoneme::Vowel { phoneme: _ }))
+            && self.0[..i].iter().rev().find(|phoneme| **phoneme != Phoneme::Stress) == current
+    }
+
+    fn is_word_inner(&self, i: usize) -> bool {
+        !matches!(self.prev(i), None | Some(Phoneme::Probel { separator: _ }))
+            && !matches!(self.next(i), None | Some(Phoneme::Probel { separator: _ }))
+    }
+
+    fn is_soft_jot(&self, i: usize) -> bool {
+        self.0.get(i) == Some(&Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J })
+            && matches!(self.prev(i), Some(Phoneme::Consonant { phoneme: _, is_palatalized: true }))
+            && matches!(self.next(i), Some(Phoneme::Vowel { phoneme: _ }))
+    }
+
+    fn is_geminate_first(&self, i: usize) -> bool {
+        match self.0.get(i) {
+            Some(current @ (
+                Phoneme::Consonant { phoneme: _, is_palatalized: _ }
+                | Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::Q }
+            )) => self.0.get(i + 1) == Some(current),
+            _ => false,
+        }
+    }
+
+    fn is_unstressed(&self, i: usize) -> bool {
+        let is_word_end = |phoneme: &Phoneme| matches!(phoneme, Phoneme::Probel { separator: _ });
+        let start = self.0[..i].iter().rposition(is_word_end).map_or(0, |end| end + 1);
+        let end = self.0[i..].iter().position(is_word_end).map_or(self.0.len(), |end| i + end);
+        self.0.get(i + 1) != Some(&Phoneme::Stress) && self.0[start..end].contains(&Phoneme::Stress)
+    }
+
+    fn is_jer(&self, i: usize) -> bool {
+        let is_soft_jot_next = self.next_index(i).is_some_and(|next| self.is_soft_jot(next));
+        matches!(self.0.get(i), Some(Phoneme::Consonant { phoneme: _, is_palatalized: true }))
+            && !self.is_vowel_next(i)
+            && !self.is_geminate_first(i)
+            && !is_soft_jot_next
+    }
+
+    fn base_letter(&self, i: usize) -> &'static str {
+        match self.0[i] {
+            Phoneme::Vowel { phoneme } => vowels_spelling(phoneme, self.is_palatalized_prev(i) && !self.is_q_or_wj_prev(i)),
+            Phoneme::Consonant { phoneme, is_palatalized } => consonants_spelling(phoneme, is_palatalized, false, false),
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J } => if self.is_soft_jot(i) {
+                "ь"
+            } else if self.is_vowel_next(i) && self.is_consonant_prev(i) {
+                "ъ"
+            } else if self.is_vowel_next(i) {
+                ""
+            } else {
+                "й"
+            },
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::Q } => "ч",
+            _ => "",
+        }
+    }
+
+    fn letter_at(&self, i: usize) -> &'static str {
+        self.spelling(i).unwrap_or_else(|| self.base_letter(i))
+    }
+
+    fn respell<F: Fn(&Self, usize, &'static str) -> Option<&'static str>>(&mut self, respell: F) {
+        let letters = (0..self.0.len())
+            .filter_map(|i| respell(self, i, self.letter_at(i)).map(|letter| (i, letter)))
+            .collect::<Vec<_>>();
+        letters.into_iter().for_each(|(i, letter)| self.spell(i, letter));
+    }
+
+    fn spelled(&self, options: &RuOptions) -> Self {
+        let mut seq = self.clone();
+        rule::orthography(options).into_iter().for_each(|rule| rule.apply(&mut seq));
+        seq
+    }
+
+    fn render_at(&self, i: usize, options: &RuOptions) -> Result<&'static str, RenderError> {
+        if let Some(letter) = self.spelling(i) {
+            return Ok(letter);
+        }
+        Ok(match self.0[i] {
+            Phoneme::Vowel { phoneme: _ }
+            | Phoneme::Consonant { phoneme: _, is_palatalized: _ }
+            | Phoneme::PalatalizedOnlyConsonant { phoneme: _ } => self.base_letter(i),
+            Phoneme::ReducedVowel => if self.is_palatalized_prev(i) { "ь" } else { "ъ" },
+            Phoneme::MorphemeBoundary => "",
+            Phoneme::SyllableBoundary => {
+                let is_hiatus = self.is_vowel_prev(i) && self.is_vowel_next(i);
+                let is_hyphenated = options.hyphenation != Hyphenation::None && self.is_word_inner(i);
+                match (is_hyphenated, is_hiatus, options.hiatus) {
+                    (true,  true,  Hiatus::InsertJot) => "-й",
+                    (true,  _,     _)                 => "-",
+                    (false, true,  Hiatus::Keep)      => "",
+                    (false, true,  Hiatus::InsertJot) => "й",
+                    (false, true,  Hiatus::Hyphen)    => "-",
+                    (false, false, _)                 => "",
+                }
+            },
+            Phoneme::Capital => "",
+            Phoneme::Override { index } => return Err(RenderError::MissingOverride { position: i, index }),
+            Phoneme::Stress => match options.stress_marks {
+                StressMarks::Omit  => "",
+                StressMarks::Acute => "\u{301}",
+            },
+            Phoneme::ClusterBreak { strategy } => match strategy {
+                ClusterStrategy::Keep        => "",
+                ClusterStrategy::EpentheticY => if self.is_palatalized_prev(i) { "и" } else { "ы" },
+                ClusterStrategy::EpentheticE => "е",
+                ClusterStrategy::Hyphen      => "-",
+            },
+            Phoneme::Probel { separator } => separator.as_str()
+        })
+    }
+
+    fn audit_at(&self, i: usize, rendered: &'static str, sources: &[Range<usize>]) -> Option<AuditEvent> {
+        let (rule, alternatives) = match (self.0.get(i).copied()?, rendered) {
+            (Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J }, "ъ") => {
+                (AuditRule::HardSign, vec!["ь"])
+            },
+            (Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J }, "ь") => {
+                (AuditRule::SoftSign, vec!["ъ"])
+            },
+            (Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J }, "и") => {
+                (AuditRule::SyllabicJot, vec!["й"])
+            },
+            (Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J }, "") => {
+                (AuditRule::SilentJot, vec!["й"])
+            },
+            (Phoneme::Consonant { phoneme: _, is_palatalized: true }, _) if rendered.ends_with('ь') => {
+                (AuditRule::SoftSign, rendered.strip_suffix('ь').into_iter().collect())
+            },
+            (Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::Q }, "чь") => {
+                (AuditRule::SoftSign, vec!["ч"])
+            },
+            (Phoneme::ReducedVowel, _) => (AuditRule::ReducedVowel, vec![""]),
+            _ => return None,
+        };
+        let span = sources.get(i).cloned().unwrap_or_default();
+        Some(AuditEvent { position: i, span, rule, output: rendered, alternatives })
+    }
+
+    fn override_at(&self, i: usize) -> Option<&str> {
+        match self.0.get(i) {
+            Some(Phoneme::Override { index }) => self.spelling(i).or_else(|| self.1.get(*index).map(|(_, ru)| ru.as_str())),
+            _ => None,
+        }
+    }
+
+    fn assemble<'a, F>(&'a self, options: &RuOptions, render_piece: F) -> Result<String, RenderError>
+    where
+        F: FnMut(usize) -> Result<&'a str, RenderError>,
+    {
+        let mut rendered = String::new();
+        self.walk(options, render_piece, |_, piece| {
+            rendered.push_str(piece);
+            Ok(())
+        })?;
+        Ok(rendered)
+    }
+
+    fn walk<'a, F, G, E>(&'a self, options: &RuOptions, mut render_piece: F, mut emit: G) -> Result<(), E>
+    where
+        F: FnMut(usize) -> Result<&'a str, E>,
+        G: FnMut(usize, &str) -> Result<(), E>,
+    {
+        let mut is_capital_pending = false;
+        (0..self.0.len()).try_for_each(|i| {
+            let piece = render_piece(i)?;
+            emit(i, &self.capitalize_at(i, piece, &mut is_capital_pending, options))
+        })
+    }
+
+    fn capitalize_at<'a>(&self, i: usize, piece: &'a str, is_capital_pending: &mut bool, options: &RuOptions) -> Cow<'a, str> {
+        let is_word_start = i == 0 || matches!(self.0[i - 1], Phoneme::Probel { separator: _ });
+        match self.0[i] {
+            Phoneme::Probel { separator: _ } => *is_capital_pending = false,
+            Phoneme::Capital => *is_capital_pending = true,
+            _ if is_word_start && options.capitalize_words => *is_capital_pending = true,
+            _ => {},
+        }
+        match piece.chars().next() {
+            Some(first) if *is_capital_pending => {
+                let mut capitalized = first.to_uppercase().collect::<String>();
+                capitalized.push_str(&piece[first.len_utf8()..]);
+                *is_capital_pending = false;
+                Cow::Owned(capitalized)
+            },
+            _ => Cow::Borrowed(piece),
+        }
+    }
+
+    fn write_normalized<E, F: FnMut(&str) -> Result<(), E>>(&self, options: &RuOptions, mut write: F) -> Result<(), E> {
+        self.walk(options, |i| Ok(self.lossy_piece_at(i, options)), |_, piece| write(&options.normalization.apply(piece)))
+    }
+
+    pub(crate) fn render(&self, options: &RuOptions) -> Result<String, RenderError> {
+        let seq = self.spelled(options);
+        seq.assemble(options, |i| seq.override_at(i).map_or_else(|| seq.render_at(i, options), Ok))
+    }
+
+    fn render_audited(
+        &self,
+        options: &RuOptions,
+        sources: &[Range<usize>],
+        sink: &mut dyn AuditSink,
+    ) -> Result<String, RenderError> {
+        let seq = self.spelled(options);
+        seq.assemble(options, |i| {
+            if let Some(piece) = seq.override_at(i) {
+                return Ok(piece);
+            }
+            let piece = seq.render_at(i, options)?;
+            if let Some(event) = seq.audit_at(i, piece, sources) {
+                sink.record(event);
+            }
+            Ok(piece)
+        })
+    }
+
+    fn lossy_piece_at(&self, i: usize, options: &RuOptions) -> &str {
+        self.override_at(i).unwrap_or_else(|| self.render_at(i, options).unwrap_or(REPLACEMENT))
+    }
+
+    fn render_with(&self, options: &RuOptions, choices: &[(usize, &'static str)]) -> String {
+        self.assemble(options, |i| {
+            Ok(choices.iter().find(|(position, _)| *position == i).map_or_else(|| self.lossy_piece_at(i, options), |(_, piece)| *piece))
+        })
+            .unwrap_or_default()
+    }
+
+    fn sources(&self, input: &str, options: &RuOptions) -> Vec<Range<usize>> {
+        let parsed = input::graphemes(input)
+            .flat_map(|(offset, grapheme)| {
+                let phonemes = Self::parse_tokens(grapheme, options).map(|seq| seq.0).unwrap_or_default();
+                phonemes.into_iter().map(move |phoneme| (phoneme, offset..offset + grapheme.len()))
+            })
+            .collect::<Vec<_>>();
+        let mut next = 0;
+        self.0.iter()
+            .map(|phoneme| match parsed[next..].iter().take(SOURCE_LOOKAHEAD).position(|(parsed, _)| parsed == phoneme) {
+                Some(skipped) => {
+                    next += skipped + 1;
+                    parsed[next - 1].1.clone()
+                },
+                None => {
+                    let end = parsed[..next].last().map_or(0, |(_, source)| source.end);
+                    end..end
+                },
+            })
+            .collect()
+    }
+
+    fn output_spans(&self, options: &RuOptions) -> Vec<Range<usize>> {
+        (0..self.0.len())
+            .scan(0, |end, i| {
+                let start = *end;
+                *end += options.normalization.apply(self.lossy_piece_at(i, options)).len();
+                Some(start..*end)
+            })
+            .collect()
+    }
+
+    fn rules_at(&self, i: usize) -> Vec<ExplainRule> {
+        let is_jot = |phoneme| phoneme == Some(Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J });
+        let Some(current) = self.0.get(i).copied() else {
+            return Vec::new();
+        };
+        let is_iotated = matches!(current, Phoneme::Vowel { phoneme: _ }) && is_jot(self.prev(i));
+        let is_geminate = self.is_geminate_first(i) || i.checked_sub(1).is_some_and(|prev| self.is_geminate_first(prev));
+        [
+            (ExplainRule::Palatalization, matches!(current, Phoneme::Consonant { phoneme: _, is_palatalized: true })),
+            (ExplainRule::Jot,            is_jot(Some(current)) || is_iotated),
+            (ExplainRule::Geminate,       is_geminate),
+            (ExplainRule::Override,       matches!(current, Phoneme::Override { index: _ })),
+        ]
+            .into_iter()
+            .filter(|(_, is_fired)| *is_fired)
+            .map(|(rule, _)| rule)
+            .collect()
+    }
+
+    fn annotation_at(&self, i: usize) -> Option<&str> {
+        match self.0.get(i)? {
+            Phoneme::Consonant { phoneme: _, is_palatalized: true } => Some("ʲ"),
+            Phoneme::ReducedVowel                                   => Some("ə"),
+            Phoneme::MorphemeBoundary                               => Some("+"),
+            Phoneme::Stress                                         => Some("ˈ"),
+            Phoneme::SyllableBoundary                               => Some("."),
+            Phoneme::Override { index }                             => self.1.get(*index).map(|(ipa, _)| ipa.as_str()),
+            _                                                       => None,
+        }
+    }
+
+    fn write_annotated(&self, formatter: &mut fmt::Formatter<'_>, options: &RuOptions) -> fmt::Result {
+        (0..self.0.len()).try_for_each(|i| {
+            formatter.write_str(self.lossy_piece_at(i, options))?;
+            match self.annotation_at(i) {
+                Some(annotation) => write!(formatter, "[{}]", annotation),
+                None => Ok(()),
+            }
+        })
+    }
+
+    fn render_lossy(&self, options: &RuOptions) -> String {
+        let seq = self.spelled(options);
+        seq.assemble(options, |i| {
+            Ok(seq.lossy_piece_at(i, options))
+        })
+            .unwrap_or_default()
+    }
+}
+
+impl From<Vec<Phoneme>> for PhonemeSeq {
+    fn from(phonemes: Vec<Phoneme>) -> Self {
+        Self(phonemes, Vec::new(), Vec::new())
+    }
+}
+
+impl FromIterator<Phoneme> for PhonemeSeq {
+    fn from_iter<I: IntoIterator<Item = Phoneme>>(phonemes: I) -> Self {
+        Self(phonemes.into_iter().collect(), Vec::new(), Vec::new())
+    }
+}
+
+impl fmt::Display for PhonemeSeq {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match formatter.alternate() {
+            true => self.spelled(&RuOptions::default()).write_annotated(formatter, &RuOptions::default()),
+            false => formatter.write_str(&self.render_lossy(&RuOptions::default())),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Ru {
+    seq: PhonemeSeq,
+    options: RuOptions,
+    text: String,
+    sources: Vec<Range<usize>>,
+    ipa: String,
+    approximations: usize,
+}
+
+impl fmt::Display for Ru {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match formatter.alternate() {
+            true => self.seq.spelled(&self.options).write_annotated(formatter, &self.options),
+            false => self.render_with(&mut PlainText, formatter),
+        }
+    }
+}
+
+impl PartialEq for Ru {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Ru {}
+
+impl Hash for Ru {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state)
+    }
+}
+
+impl PartialOrd for Ru {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ru {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl Ru {
+    fn key(&self) -> (&[Phoneme], &[(String, String)]) {
+        (self.seq.phonemes(), self.seq.overrides())
+    }
+
+    fn new(seq: PhonemeSeq) -> Self {
+        Self::with_parts(seq, RuOptions::default())
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_phonemes(phonemes: Vec<Phoneme>, overrides: Vec<(String, String)>, options: RuOptions) -> Self {
+        Self::with_parts(PhonemeSeq(phonemes, overrides, Vec::new()), options)
+    }
+
+    pub fn from_seq(seq: PhonemeSeq, options: RuOptions) -> Self {
+        Self::with_parts(seq, options)
+    }
+
+    fn with_parts(seq: PhonemeSeq, options: RuOptions) -> Self {
+        let mut text = String::new();
+        let _ = seq.spelled(&options).write_normalized(&options, |piece| fmt::Write::write_str(&mut text, piece));
+        Self { seq, options, text, sources: Vec::new(), ipa: String::new(), approximations: 0 }
+    }
+
+    pub fn builder() -> RuBuilder {
+        RuBuilder::new()
+    }
+
+    pub fn with_options(self, options: RuOptions) -> Self {
+        let Self { seq, options: _, text: _, sources, ipa, approximations } = self;
+        Self { sources, ipa, approximations, ..Self::with_parts(seq, options) }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn ipa(&self) -> &str {
+        &self.ipa
+    }
+
+    pub fn into_string(self) -> String {
+        self.text
+    }
+
+    pub fn push_ipa(&mut self, ipa_str: &str) -> Result<(), Error> {
+        let other = Self::try_from_ipa_with(ipa_str, self.options.clone())?;
+        let this = mem::replace(self, Self::new(PhonemeSeq::default()));
+        *self = this + other;
+        Ok(())
+    }
+
+    pub fn concat(rus: &[Ru]) -> Self {
+        rus.iter().cloned().reduce(Add::add).unwrap_or_else(|| Self::new(PhonemeSeq::default()))
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = RuWord> + '_ {
+        self.seq.0
+            .split(|phoneme| matches!(phoneme, Phoneme::Probel { separator: _ }))
+            .scan(0, |end, phonemes| {
+                let start = *end;
+                *end += phonemes.len() + 1;
+                Some((start, phonemes))
+            })
+            .filter(|(_, phonemes)| !phonemes.is_empty())
+            .map(|(start, phonemes)| {
+                let end = start + phonemes.len();
+                let spellings = self.seq.2.get(start..end).map(<[_]>::to_vec).unwrap_or_default();
+                let seq = PhonemeSeq(phonemes.to_vec(), self.seq.1.clone(), spellings);
+                let sources = self.sources.get(start..end).unwrap_or_default();
+                let offset = sources.first().map_or(0, |first| first.start);
+                let ipa = sources.last().and_then(|last| self.ipa.get(offset..last.end)).unwrap_or_default();
+                let sources = sources.iter().map(|source| source.start - offset..source.end - offset).collect();
+                let ru = Self { sources, ipa: ipa.to_owned(), ..Self::with_parts(seq, self.options.clone()) };
+                RuWord::from_ru(offset, ru)
+            })
+    }
+
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.text.chars()
+    }
+
+    #[cfg(feature = "std")]
+    pub fn write_to<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        self.seq.spelled(&self.options).write_normalized(&self.options, |piece| writer.write_all(piece.as_bytes()))
+    }
+
+    pub fn write_to_fmt<W: fmt::Write + ?Sized>(&self, writer: &mut W) -> fmt::Result {
+        self.seq.spelled(&self.options).write_normalized(&self.options, |piece| writer.write_str(piece))
+    }
+
+    pub fn options(&self) -> &RuOptions {
+        &self.options
+    }
+
+    pub fn phonemes(&self) -> &[Phoneme] {
+        &self.seq.0
+    }
+
+    pub fn seq(&self) -> &PhonemeSeq {
+        &self.seq
+    }
+
+    pub fn into_seq(self) -> PhonemeSeq {
+        self.seq
+    }
+
+    pub fn map_phonemes<F: FnMut(Phoneme) -> Phoneme>(self, map: F) -> Self {
+        let Self { seq, options, text: _, sources, ipa, approximations } = self;
+        let seq = PhonemeSeq(seq.0.into_iter().map(map).collect(), seq.1, seq.2);
+        Self { sources, ipa, approximations, ..Self::with_parts(seq, options) }
+    }
+
+    pub fn filter_phonemes<F: FnMut(&Phoneme) -> bool>(self, mut keep: F) -> Self {
+        let Self { seq, options, text: _, sources, ipa, approximations } = self;
+        let kept = seq.0.iter().map(&mut keep).collect::<Vec<_>>();
+        let seq = PhonemeSeq(retained(seq.0, &kept), seq.1, retained(seq.2, &kept));
+        let sources = retained(sources, &kept);
+        Self { sources, ipa, approximations, ..Self::with_parts(seq, options) }
+    }
+
+    pub fn distance(&self, other: &Ru) -> usize {
+        let phonemes = |ru: &Ru| ru.seq.0.iter().copied().filter(|phoneme| !phoneme.is_marker()).collect::<Vec<_>>();
+        let (these, others) = (phonemes(self), phonemes(other));
+        let first_row = (0..=others.len()).map(|j| j * INDEL_COST).collect::<Vec<_>>();
+        let last_row = these.iter().enumerate().fold(first_row, |previous, (i, phoneme)| {
+            others.iter().enumerate().fold(vec![(i + 1) * INDEL_COST], |mut row, (j, other)| {
+                let cost = (previous[j] + phoneme.substitution_cost(*other))
+                    .min(previous[j + 1] + INDEL_COST)
+                    .min(row[j] + INDEL_COST);
+                row.push(cost);
+                row
+            })
+        });
+        last_row[others.len()]
+    }
+
+    pub fn alignment(&self) -> Vec<(Range<usize>, Range<usize>)> {
+        self.explain().into_iter().map(|step| (step.ipa, step.ru)).collect()
+    }
+
+    pub fn explain(&self) -> Vec<ExplainStep> {
+        let seq = self.seq.spelled(&self.options);
+        let outputs = seq.output_spans(&self.options);
+        self.sources.iter().cloned().zip(outputs).enumerate().fold(Vec::new(), |mut steps, (i, (ipa, ru))| {
+            let rules = seq.rules_at(i);
+            match steps.last_mut() {
+                _ if ipa.is_empty() && ru.is_empty() => {},
+                Some(last) if last.ipa == ipa => {
+                    last.ru.end = ru.end;
+                    for rule in rules {
+                        if !last.rules.contains(&rule) {
+                            last.rules.push(rule);
+                        }
+                    }
+                },
+                _ => steps.push(ExplainStep { ipa, ru, rules }),
+            }
+            steps
+        })
+    }
+
+    pub fn render(&self) -> Result<String, RenderError> {
+        self.seq.render(&self.options)
+            .map(|rendered| self.options.normalization.apply(&rendered))
+            .inspect_err(|error| metrics::observe_error(Error::from(*error).code()))
+    }
+
+    pub fn render_with<R: Render + ?Sized>(&self, renderer: &mut R, output: &mut dyn fmt::Write) -> fmt::Result {
+        let options = &self.options;
+        let seq = self.seq.spelled(options);
+        seq.walk(options, |i| Ok(seq.lossy_piece_at(i, options)), |i, piece| {
+            let phoneme = seq.0[i];
+            let piece = options.normalization.apply(piece);
+            match phoneme {
+                Phoneme::Vowel { phoneme: _ } | Phoneme::ReducedVowel => renderer.vowel(output, phoneme, &piece),
+                Phoneme::Consonant { phoneme: _, is_palatalized: _ } | Phoneme::PalatalizedOnlyConsonant { phoneme: _ } => {
+                    renderer.consonant(output, phoneme, &piece)
+                },
+                Phoneme::MorphemeBoundary
+                | Phoneme::SyllableBoundary
+                | Phoneme::ClusterBreak { strategy: _ }
+                | Phoneme::Probel { separator: _ } => renderer.boundary(output, phoneme, &piece),
+                Phoneme::Stress | Phoneme::Capital | Phoneme::Override { index: _ } => renderer.other(output, phoneme, &piece),
+            }
+        })
+    }
+
+    pub fn render_audited(&self, sink: &mut dyn AuditSink) -> Result<String, RenderError> {
+        self.seq.render_audited(&self.options, &self.sources, sink).map(|rendered| self.options.normalization.apply(&rendered))
+    }
+
+    fn alternatives_at(&self, seq: &PhonemeSeq, i: usize) -> Vec<&'static str> {
+        let ipa = match seq.0.get(i) {
+            Some(Phoneme::Override { index }) => seq.1.get(*index).map(|(ipa, _)| ipa.as_str()),
+            Some(Phoneme::Vowel { phoneme: _ }) => self.sources.get(i).and_then(|source| self.ipa.get(source.clone())),
+            _ => None,
+        };
+        let symbol = ipa.unwrap_or_default().trim_matches(|symbol| matches!(symbol, 'ː' | 'ˈ' | 'ˌ'));
+        let Some((_, letters)) = ALTERNATIVES.iter().find(|(ipa, _)| *ipa == symbol) else {
+            return Vec::new();
+        };
+        let current = seq.override_at(i).or_else(|| seq.render_at(i, &self.options).ok()).unwrap_or_default();
+        let is_soft = hardened(current).is_some();
+        letters.iter().fold(Vec::new(), |mut alternatives, letter| {
+            let letter = if is_soft { softened(letter) } else { *letter };
+            if letter != current && !alternatives.contains(&letter) {
+                alternatives.push(letter);
+            }
+            alternatives
+        })
+    }
+
+    pub fn candidates(&self, limit: usize) -> Vec<String> {
+        let mut events = Vec::new();
+        let _ = self.seq.render_audited(&self.options, &self.sources, &mut events);
+        let seq = self.seq.spelled(&self.options);
+        let choices = events.iter()
+            .map(|event| (event.position, event.alternatives.clone()))
+            .chain((0..seq.0.len()).map(|i| (i, self.alternatives_at(&seq, i))))
+            .filter(|(_, alternatives)| !alternatives.is_empty())
+            .collect::<Vec<_>>();
+        let mut frontier = BTreeSet::from([(0, vec![0; choices.len()])]);
+        let mut candidates = Vec::new();
+        while candidates.len() < limit {
+            let Some((cost, picks)) = frontier.pop_first() else { break };
+            let pieces = choices.iter()
+                .zip(&picks)
+                .filter(|(_, pick)| **pick > 0)
+                .map(|((position, alternatives), pick)| (*position, alternatives[*pick - 1]))
+                .collect::<Vec<_>>();
+            let candidate = self.options.normalization.apply(&seq.render_with(&self.options, &pieces));
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+            (0..picks.len()).filter(|k| picks[*k] < choices[*k].1.len()).for_each(|k| {
+                let mut next = picks.clone();
+                next[k] += 1;
+                frontier.insert((cost + 1, next));
+            });
+        }
+        candidates
+    }
+
+    pub fn confidence(&self) -> f32 {
+        let sounds = self.seq.0.iter().filter(|phoneme| phoneme.is_sound()).count();
+        let mut events = Vec::new();
+        let _ = self.seq.render_audited(&self.options, &self.sources, &mut events);
+        let unrendered = (0..self.seq.0.len())
+            .filter(|i| self.seq.override_at(*i).is_none() && self.seq.render_at(*i, &self.options).is_err())
+            .count();
+        match sounds {
+            0 => 1.0,
+            _ => {
+                let lossy = events.iter().filter(|event| event.rule.is_lossy()).count();
+                (1.0 - (self.approximations + lossy + unrendered) as f32 / sounds as f32).max(0.0)
+            },
+        }
+    }
+
+    pub fn from_sounds<I: IntoIterator<Item = ipa_sounds::Sound>>(sounds: I, options: RuOptions) -> Self {
+        let mut seq = PhonemeSeq::from_sounds(&sounds.into_iter().collect::<Vec<_>>());
+        rule::builtin(&options).into_iter().for_each(|rule| rule.apply(&mut seq));
+        Self::with_parts(seq, options)
+    }
+
+    pub fn try_from_ipa(ipa_str: &str) -> Result<Self, Error> {
+        Self::try_from_ipa_with(ipa_str, RuOptions::default())
+    }
+
+    pub fn try_from_ipa_with(ipa_str: &str, options: RuOptions) -> Result<Self, Error> {
+        Self::try_from_ipa_with_rules(ipa_str, options, &[])
+    }
+
+    pub fn try_from_ipa_with_rules(ipa_str: &str, options: RuOptions, rules: &[&dyn RuRule]) -> Result<Self, Error> {
+        metrics::timed(|| {
+            options.validate()?;
+            let seq = PhonemeSeq::parse(ipa_str, &options, rules)?;
+            let sources = seq.sources(ipa_str, &options);
+            let approximations = report::count(ipa_str, &options);
+            Ok(Self { sources, ipa: ipa_str.to_owned(), approximations, ..Self::with_parts(seq, options) })
+        })
+    }
+
+    pub fn try_from_ipa_reported(ipa_str: &str, options: RuOptions) -> Result<(Self, ConversionReport), Error> {
+        let report = report::report(ipa_str, &options);
+        Self::try_from_ipa_with(ipa_str, options).map(|ru| (ru, report))
+    }
+
+    pub fn try_from_lossy(ipa_str: &str, options: RuOptions, placeholder: &str) -> (String, Vec<Range<usize>>) {
+        let mut text = String::new();
+        let mut skipped = Vec::new();
+        let mut start = 0;
+        for (offset, grapheme) in input::graphemes(ipa_str).chain(iter::once((ipa_str.len(), ""))) {
+            let is_end = grapheme.is_empty();
+            if !is_end && PhonemeSeq::is_valid_grapheme(grapheme, &options) {
+                continue;
+            }
+            if start < offset {
+                match Self::try_from_ipa_with(&ipa_str[start..offset], options.clone()) {
+                    Ok(ru) => text.push_str(ru.as_str()),
+                    Err(_) => {
+                        text.push_str(placeholder);
+                        skipped.push(start..offset);
+                    },
+                }
+            }
+            if !is_end {
+                text.push_str(placeholder);
+                skipped.push(offset..offset + grapheme.len());
+            }
+            start = offset + grapheme.len();
+        }
+        (text, skipped)
+    }
+
+    pub fn try_from_xsampa(xsampa_str: &str) -> Result<Self, Error> {
+        Self::try_from_ipa(&xsampa::to_ipa(xsampa_str)?)
+    }
+
+    pub fn try_from_cxs(cxs_str: &str) -> Result<Self, Error> {
+        Self::try_from_ipa(&xsampa::cxs_to_ipa(cxs_str)?)
+    }
+
+    pub fn try_from_kirshenbaum(kirshenbaum_str: &str) -> Result<Self, Error> {
+        Self::try_from_ipa(&kirshenbaum::to_ipa(kirshenbaum_str)?)
+    }
+
+    pub fn try_from_arpabet(arpabet_str: &str) -> Result<Self, Error> {
+        Self::try_from_ipa(&arpabet::to_ipa(arpabet_str)?)
+    }
+
+    pub fn try_from_tipa(tipa_str: &str) -> Result<Self, Error> {
+        Self::try_from_ipa(&tipa::to_ipa(tipa_str)?)
+    }
+
+    pub fn try_from_g2p<S: G2pSource + ?Sized>(source: &S, options: RuOptions) -> Result<Self, Error> {
+        Self::try_from_ipa_with(&g2p::to_ipa(source)?, options)
+    }
+}
+
+impl Add for Ru {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let sourced = |ru: &Self| match ru.sources.len() == ru.seq.0.len() {
+            true => ru.sources.clone(),
+            false => vec![ru.ipa.len()..ru.ipa.len(); ru.seq.0.len()],
+        };
+        let (mut sources, others) = (sourced(&self), sourced(&other));
+        let seq = self.seq.join(other.seq);
+        let mut ipa = self.ipa;
+        if seq.0.len() > sources.len() + others.len() {
+            sources.push(ipa.len()..ipa.len() + 1);
+            ipa.push(' ');
+        }
+        let offset = ipa.len();
+        sources.extend(others.into_iter().map(|source| source.start + offset..source.end + offset));
+        ipa.push_str(&other.ipa);
+        Self {
+            sources,
+            ipa,
+            approximations: self.approximations + other.approximations,
+            ..Self::with_parts(seq, self.options)
+        }
+    }
+}
+
+impl From<ipa_sounds::Ipa> for Ru {
+    fn from(ipa: ipa_sounds::Ipa) -> Self {
+        Self::new(PhonemeSeq::new(ipa))
+    }
+}
+
+impl From<&[ipa_sounds::Sound]> for Ru {
+    fn from(sounds: &[ipa_sounds::Sound]) -> Self {
+        Self::new(PhonemeSeq::from_sounds(sounds))
+    }
+}
+
+impl TryFrom<&str> for Ru {
+    type Error = Error;
+
+    fn try_from(ipa_str: &str) -> Result<Self, Self::Error> {
+        Self::try_from_ipa(ipa_str)
+    }
+}
+
+impl TryFrom<String> for Ru {
+    type Error = Error;
+
+    fn try_from(ipa_string: String) -> Result<Self, Self::Error> {
+        Self::try_from(ipa_string.as_str())
+    }
+}
+
+#[cfg(test)]
+mod ru_phoneme_seq_fmt_tests {
+    use super::*;
+    use crate::options::{HushingSoftSign, QSpelling};
+
+    #[test]
+    fn test_na() {
+        assert_eq!(format!("{}", PhonemeSeq::from(vec![
+            Phoneme::Consonant { phoneme: Consonants::N, is_palatalized: true },
+            Phoneme::Vowel { phoneme: Vowels::A },
+        ])), "ня");
+    }
+
+    #[test]
+    fn test_na_annotated() {
+        assert_eq!(format!("{:#}", PhonemeSeq::from(vec![
+            Phoneme::Consonant { phoneme: Consonants::N, is_palatalized: true },
+            Phoneme::Vowel { phoneme: Vowels::A },
+        ])), "н[ʲ]я");
+    }
+
+    #[test]
+    fn test_jer() {
+        assert_eq!(format!("{}", PhonemeSeq::from(vec![
+            Phoneme::Consonant { phoneme: Consonants::P, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::O },
+            Phoneme::Consonant { phoneme: Consonants::D, is_palatalized: false },
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J },
+            Phoneme::Vowel { phoneme: Vowels::E },
+            Phoneme::Consonant { phoneme: Consonants::Z, is_palatalized: false },
+            Phoneme::Consonant { phoneme: Consonants::D, is_palatalized: false },
+        ])), "подъезд");
+    }
+
+    #[test]
+    fn test_huj() {
+        assert_eq!(format!("{}", PhonemeSeq::from(vec![
+            Phoneme::Consonant { phoneme: Consonants::H, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::U },
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J },
+        ])), "хуй");
+    }
+
+    #[test]
+    fn test_intervokalnij_jot() {
+        assert_eq!(format!("{}", PhonemeSeq::from(vec![
+            Phoneme::Vowel { phoneme: Vowels::A },
+            Phoneme::Consonant { phoneme: Consonants::H, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::U },
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J },
+            Phoneme::Vowel { phoneme: Vowels::E },
+            Phoneme::Consonant { phoneme: Consonants::T, is_palatalized: true },
+        ])), "ахуеть");
+    }
+
+    #[test]
+    fn test_naqalnij_jot() {
+        assert_eq!(format!("{}", PhonemeSeq::from(vec![
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J },
+            Phoneme::Vowel { phoneme: Vowels::E },
+            Phoneme::Consonant { phoneme: Consonants::B, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::A },
+            Phoneme::Consonant { phoneme: Consonants::T, is_palatalized: true },
+        ])), "ебать");
+    }
+
+    #[test]
+    fn test_wuwa() {
+        assert_eq!(format!("{}", PhonemeSeq::from(vec![
+            Phoneme::Consonant { phoneme: Consonants::W, is_palatalized: true },
+            Phoneme::Vowel { phoneme: Vowels::U },
+            Phoneme::Consonant { phoneme: Consonants::W, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::A },
+        ])), "щуша");
+    }
+
+    #[test]
+    fn test_jot_positions() {
+        let seq = PhonemeSeq::from(vec![
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J },
+            Phoneme::Consonant { phoneme: Consonants::T, is_palatalized: false },
+            Phoneme::Consonant { phoneme: Consonants::R, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::I },
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J },
+            Phoneme::Probel { separator: Separator::Space },
+            Phoneme::Consonant { phoneme: Consonants::P, is_palatalized: false },
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J },
+            Phoneme::Consonant { phoneme: Consonants::T, is_palatalized: false },
+        ]);
+        assert_eq!(format!("{}", seq), "йтрый пйт");
+        let options = RuOptions {
+            jot_rules: JotRules { between_consonants: JotSpelling::I, word_initial: JotSpelling::I, ..JotRules::default() },
+            ..RuOptions::default()
+        };
+        assert_eq!(seq.render(&options), Ok("итрый пит".to_owned()));
+    }
+
+    #[test]
+    fn test_jot_onset_spelled() {
+        let seq = PhonemeSeq::from(vec![
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J },
+            Phoneme::Vowel { phoneme: Vowels::O },
+            Phoneme::Consonant { phoneme: Consonants::R, is_palatalized: false },
+            Phoneme::Consonant { phoneme: Consonants::K, is_palatalized: false },
+        ]);
+        let options = RuOptions {
+            jot_rules: JotRules { onset: JotSpelling::ShortI, ..JotRules::default() },
+            ..RuOptions::default()
+        };
+        assert_eq!(seq.render(&options), Ok("йорк".to_owned()));
+        assert_eq!(seq.render(&RuOptions::default()), Ok("ёрк".to_owned()));
+    }
+
+    #[test]
+    fn test_zhi_shi() {
+        let seq = PhonemeSeq::from(vec![
+            Phoneme::Consonant { phoneme: Consonants::X, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::I },
+            Phoneme::Consonant { phoneme: Consonants::W, is_palatalized: false },
+            Phoneme::Consonant { phoneme: Consonants::W, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::I },
+            Phoneme::Consonant { phoneme: Consonants::W, is_palatalized: true },
+            Phoneme::Vowel { phoneme: Vowels::I },
+        ]);
+        assert_eq!(format!("{}", seq), "жишшищи");
+        let options = RuOptions { orthography: Orthography::Phonetic, ..RuOptions::default() };
+        assert_eq!(seq.render(&options), Ok("жышшыщы".to_owned()));
+    }
+
+    #[test]
+    fn test_q_positions() {
+        let seq = PhonemeSeq::from(vec![
+            Phoneme::Vowel { phoneme: Vowels::O },
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::Q },
+            Phoneme::Consonant { phoneme: Consonants::K, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::I },
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::Q },
+        ]);
+        assert_eq!(format!("{}", seq), "очкыч");
+        let options = RuOptions {
+            q_rules: QRules { word_final: QSpelling::ChSoftSign, ..QRules::default() },
+            ..RuOptions::default()
+        };
+        assert_eq!(seq.render(&options), Ok("очкычь".to_owned()));
+    }
+
+    #[test]
+    fn test_qa_wa_qu_wu() {
+        let seq = PhonemeSeq::from(vec![
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::Q },
+            Phoneme::Vowel { phoneme: Vowels::A },
+            Phoneme::Consonant { phoneme: Consonants::W, is_palatalized: false },
+            Phoneme::Consonant { phoneme: Consonants::K, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::A },
+            Phoneme::Probel { separator: Separator::Space },
+            Phoneme::Consonant { phoneme: Consonants::W, is_palatalized: true },
+            Phoneme::Vowel { phoneme: Vowels::U },
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::Q },
+            Phoneme::Vowel { phoneme: Vowels::E },
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::Q },
+            Phoneme::Vowel { phoneme: Vowels::I },
+        ]);
+        assert_eq!(format!("{}", seq), "чашка щучечи");
+        let options = RuOptions { orthography: Orthography::Phonetic, ..RuOptions::default() };
+        assert_eq!(seq.render(&options), Ok("чашка щучэчы".to_owned()));
+    }
+
+    #[test]
+    fn test_tse() {
+        let seq = PhonemeSeq::from(vec![
+            Phoneme::Consonant { phoneme: Consonants::C, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::I },
+            Phoneme::Consonant { phoneme: Consonants::R, is_palatalized: false },
+            Phoneme::Consonant { phoneme: Consonants::K, is_palatalized: false },
+        ]);
+        assert_eq!(format!("{}", seq), "цирк");
+        let options = RuOptions { tse_vowel: TseVowel::Y, ..RuOptions::default() };
+        assert_eq!(seq.render(&options), Ok("цырк".to_owned()));
+        let options = RuOptions { orthography: Orthography::Phonetic, ..RuOptions::default() };
+        assert_eq!(seq.render(&options), Ok("цырк".to_owned()));
+    }
+
+    #[test]
+    fn test_yo_policy() {
+        let seq = PhonemeSeq::from(vec![
+            Phoneme::Consonant { phoneme: Consonants::L, is_palatalized: true },
+            Phoneme::Vowel { phoneme: Vowels::O },
+            Phoneme::Consonant { phoneme: Consonants::T, is_palatalized: false },
+        ]);
+        assert_eq!(format!("{}", seq), "лёт");
+        let options = RuOptions { yo_policy: YoPolicy::Ye, ..RuOptions::default() };
+        assert_eq!(seq.render(&options), Ok("лет".to_owned()));
+    }
+
+    #[test]
+    fn test_cluster_strategies() {
+        let seq = PhonemeSeq::from(vec![
+            Phoneme::Consonant { phoneme: Consonants::M, is_palatalized: false },
+            Phoneme::Consonant { phoneme: Consonants::C, is_palatalized: false },
+            Phoneme::Consonant { phoneme: Consonants::V, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::A },
+            Phoneme::Consonant { phoneme: Consonants::N, is_palatalized: true },
+            Phoneme::Vowel { phoneme: Vowels::E },
+        ]);
+        let repaired = |strategy| seq.clone().repair_clusters(&ClusterRules { max_len: 2, strategy });
+        assert_eq!(format!("{}", repaired(ClusterStrategy::Keep)), "мцване");
+        assert_eq!(format!("{}", repaired(ClusterStrategy::EpentheticY)), "мцыване");
+        assert_eq!(format!("{}", repaired(ClusterStrategy::EpentheticE)), "мцеване");
+        assert_eq!(format!("{}", repaired(ClusterStrategy::Hyphen)), "мц-ване");
+    }
+
+    #[test]
+    fn test_affricates() {
+        let seq = PhonemeSeq::from(vec![
+            Phoneme::Consonant { phoneme: Consonants::DX, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::I },
+            Phoneme::Consonant { phoneme: Consonants::DX, is_palatalized: false },
+            Phoneme::Consonant { phoneme: Consonants::DX, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::A },
+            Phoneme::Consonant { phoneme: Consonants::DZ, is_palatalized: true },
+        ]);
+        assert_eq!(format!("{}", seq), "джиджджадзь");
+        let decomposed = seq.decompose_affricates(Affricates::Decomposed);
+        assert_eq!(format!("{}", decomposed), "джиджжадзь");
+        let options = RuOptions { geminates: Geminates::Single, ..RuOptions::default() };
+        assert_eq!(decomposed.render(&options), Ok("джиджадзь".to_owned()));
+    }
+
+    #[test]
+    fn test_final_devoicing() {
+        let seq = PhonemeSeq::from(vec![
+            Phoneme::Consonant { phoneme: Consonants::S, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::A },
+            Phoneme::Consonant { phoneme: Consonants::T, is_palatalized: false },
+            Phoneme::Probel { separator: Separator::Space },
+            Phoneme::Consonant { phoneme: Consonants::N, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::O },
+            Phoneme::Consonant { phoneme: Consonants::W, is_palatalized: false },
+        ]);
+        assert_eq!(format!("{}", seq), "сат нош");
+        let options = RuOptions { final_devoicing: FinalDevoicing::Restore, ..RuOptions::default() };
+        assert_eq!(seq.render(&options), Ok("сад нож".to_owned()));
+    }
+
+    #[test]
+    fn test_jot_after_consonant_auto() {
+        let word = |phonemes: &[Phoneme]| {
+            let mut seq = phonemes.to_vec();
+            seq.insert(phonemes.len() - 2, Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J });
+            PhonemeSeq::from(seq)
+        };
+        let consonant = |phoneme| Phoneme::Consonant { phoneme, is_palatalized: false };
+        let vowel = |phoneme| Phoneme::Vowel { phoneme };
+        let podjezd = word(&[
+            consonant(Consonants::P), vowel(Vowels::O), consonant(Consonants::D), vowel(Vowels::E), consonant(Consonants::Z),
+        ]);
+        let sjezd = word(&[consonant(Consonants::S), vowel(Vowels::E), consonant(Consonants::Z)]);
+        let pjesa = word(&[consonant(Consonants::P), vowel(Vowels::E), consonant(Consonants::S)]);
+        let options = RuOptions { jot_after_consonant: JotAfterConsonant::Auto, ..RuOptions::default() };
+        assert_eq!(podjezd.render(&options), Ok("подъез".to_owned()));
+        assert_eq!(sjezd.render(&options), Ok("съез".to_owned()));
+        assert_eq!(pjesa.render(&options), Ok("пьес".to_owned()));
+        let options = RuOptions { jot_after_consonant: JotAfterConsonant::SoftSign, ..RuOptions::default() };
+        assert_eq!(podjezd.render(&options), Ok("подьез".to_owned()));
+    }
+
+    #[test]
+    fn test_hushing_soft_sign() {
+        let seq = PhonemeSeq::from(vec![
+            Phoneme::Vowel { phoneme: Vowels::O },
+            Phoneme::Consonant { phoneme: Consonants::X, is_palatalized: true },
+            Phoneme::Probel { separator: Separator::Space },
+            Phoneme::Consonant { phoneme: Consonants::C, is_palatalized: true },
+            Phoneme::Consonant { phoneme: Consonants::W, is_palatalized: false },
+        ]);
+        assert_eq!(format!("{}", seq), "ож цш");
+        let options = RuOptions { hushing_soft_sign: HushingSoftSign::Grammatical, ..RuOptions::default() };
+        assert_eq!(seq.render(&options), Ok("ожь цьш".to_owned()));
+    }
+
+    #[test]
+    fn test_qakra() {
+        assert_eq!(format!("{}", PhonemeSeq::from(vec![
+            Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::Q },
+            Phoneme::Vowel { phoneme: Vowels::A },
+            Phoneme::Consonant { phoneme: Consonants::K, is_palatalized: false },
+            Phoneme::Consonant { phoneme: Consonants::R, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::A },
+        ])), "чакра");
+    }
+}
+
+#[cfg(test)]
+mod ru_integration_tests {
+    use super::*;
+    use crate::g2p::EspeakPhonemes;
+    use crate::options::{NormalizationForm, VowelLength};
+
+    #[test]
+    fn test_na() {
+        assert_eq!(
+            Ru::try_from("nʲæ").map(|ru| format!("{}", ru)),
+            Ok("ня".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_annotated() {
+        assert_eq!(
+            Ru::try_from("nʲæ+mʲæn").map(|ru| (format!("{:#}", ru), ru.to_string())),
+            Ok(("н[ʲ]я[+]м[ʲ]ян".to_owned(), "нямян".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_edit_seq() {
+        let mut seq = Ru::try_from("nʲæn").unwrap().into_seq();
+        assert_eq!(seq.remove(2), Phoneme::Consonant { phoneme: Consonants::N, is_palatalized: false });
+        seq.insert(0, Phoneme::Capital);
+        assert_eq!(seq.splice(1..2, [Phoneme::Consonant { phoneme: Consonants::M, is_palatalized: true }]).len(), 1);
+        seq.retain(|phoneme| *phoneme != Phoneme::Capital);
+        assert_eq!(seq.len(), 2);
+        assert_eq!(Ru::from_seq(seq, RuOptions::default()).to_string(), "мя");
+        assert!(PhonemeSeq::from_iter([]).is_empty());
+    }
+
+    #[test]
+    fn test_alignment() {
+        let ru = Ru::try_from("nʲæːn pʲu").unwrap();
+        assert_eq!(ru.alignment(), vec![(0..3, 0..2), (3..7, 2..6), (7..8, 6..8), (8..9, 8..9), (9..12, 9..11), (12..13, 11..13)]);
+        assert_eq!(&ru.as_str()[2..6], "яа");
+        assert!(Ru::from_seq(ru.into_seq(), RuOptions::default()).alignment().is_empty());
+    }
+
+    #[test]
+    fn test_explain() {
+        let ru = Ru::try_from("jæ pʲːæ").unwrap();
+        let rules = ru.explain().into_iter().map(|step| step.rules).collect::<Vec<_>>();
+        assert_eq!(rules, vec![
+            vec![ExplainRule::Jot],
+            vec![ExplainRule::Jot],
+            vec![],
+            vec![ExplainRule::Palatalization, ExplainRule::Geminate],
+            vec![],
+        ]);
+    }
+
+    #[test]
+    fn test_candidates() {
+        let ru = Ru::try_from("pjæ nʲ").unwrap();
+        assert_eq!(ru.candidates(5), vec!["пъя нь".to_owned(), "пъя н".to_owned(), "пья нь".to_owned(), "пья н".to_owned()]);
+        assert_eq!(ru.candidates(1), vec![ru.to_string()]);
+        assert!(ru.candidates(0).is_empty());
+    }
+
+    #[test]
+    fn test_candidates_of_ambiguous_phonemes() {
+        let overrides = [("θ", "т"), ("w", "в"), ("h", "х")];
+        let overrides = overrides.iter().map(|(ipa, ru)| ((*ipa).to_owned(), (*ru).to_owned())).collect();
+        let options = RuOptions { overrides, ..RuOptions::default() };
+        let candidates = |ipa: &str| Ru::try_from_ipa_with(ipa, options.clone()).unwrap().candidates(5);
+        assert_eq!(candidates("θæm"), vec!["там".to_owned(), "сам".to_owned(), "фам".to_owned()]);
+        assert_eq!(candidates("wæn"), vec!["ван".to_owned(), "уан".to_owned()]);
+        assert_eq!(candidates("hæm"), vec!["хам".to_owned(), "гам".to_owned()]);
+        assert_eq!(candidates("pə"), vec!["па".to_owned(), "пе".to_owned(), "по".to_owned(), "пэ".to_owned()]);
+        assert_eq!(candidates("nʲə"), vec!["ня".to_owned(), "не".to_owned(), "нё".to_owned()]);
+        assert_eq!(candidates("θə")[..3], ["та".to_owned(), "те".to_owned(), "са".to_owned()]);
+    }
+
+    #[test]
+    fn test_confidence() {
+        assert_eq!(Ru::try_from("nʲæn").map(|ru| ru.confidence()), Ok(1.0));
+        assert_eq!(Ru::try_from("nʲʊ").map(|ru| ru.confidence()), Ok(0.5));
+        assert_eq!(Ru::try_from("pjæ pʲæ").map(|ru| ru.confidence()), Ok(1.0));
+        assert_eq!(Ru::try_from(" ").map(|ru| ru.confidence()), Ok(1.0));
+        let options = RuOptions { jot_rules: JotRules { coda: JotSpelling::Omit, ..JotRules::default() }, ..RuOptions::default() };
+        assert_eq!(Ru::try_from_ipa_with("nʲæj", options).map(|ru| ru.confidence()), Ok(1.0 - 1.0 / 3.0));
+    }
+
+    #[test]
+    fn test_confidence_of_exact_palatalization() {
+        assert_eq!(Ru::try_from("nʲ").map(|ru| ru.confidence()), Ok(1.0));
+        assert_eq!(Ru::try_from("nʲænʲ").map(|ru| ru.confidence()), Ok(1.0));
+        assert_eq!(Ru::try_from("pʲæ mʲæ").map(|ru| ru.confidence()), Ok(1.0));
+    }
+
+    #[test]
+    fn test_from_sounds() {
+        let sounds = [
+            ipa_sounds::Sound::Consonant {
+                phoneme: ipa_sounds::Consonants::VoicedAlveolarNasal,
+                is_long: false,
+                is_palatalized: true,
+            },
+            ipa_sounds::Sound::Vowel { phoneme: ipa_sounds::Vowels::NearOpenFrontUrounded, is_long: false },
+        ];
+        assert_eq!(Ru::from(&sounds[..]).as_str(), "ня");
+        assert_eq!(Ru::from_sounds(sounds, RuOptions::default()).phonemes(), Ru::try_from("nʲæ").unwrap().phonemes());
+    }
+
+    #[test]
+    fn test_map_phonemes() {
+        let harden = |phoneme| match phoneme {
+            Phoneme::Consonant { phoneme, is_palatalized: _ } => Phoneme::Consonant { phoneme, is_palatalized: false },
+            phoneme => phoneme,
+        };
+        assert_eq!(Ru::try_from("nʲæ mʲ").unwrap().map_phonemes(harden).as_str(), "на м");
+        let ru = Ru::try_from("pʲæːn").unwrap();
+        let ru = ru.filter_phonemes(|phoneme| !matches!(phoneme, Phoneme::Vowel { phoneme: _ }));
+        assert_eq!(ru.as_str(), "пьн");
+        assert_eq!(ru.alignment(), vec![(0..3, 0..4), (7..8, 4..6)]);
+    }
+
+    #[test]
+    fn test_distance() {
+        let nan = Ru::try_from("nʲæn").unwrap();
+        assert_eq!(nan.distance(&Ru::try_from("ˈnʲæn").unwrap()), 0);
+        assert_eq!(nan.distance(&Ru::try_from("næn").unwrap()), 1);
+        assert_eq!(nan.distance(&Ru::try_from("nʲun").unwrap()), 1);
+        assert_eq!(nan.distance(&Ru::try_from("nʲæ").unwrap()), 2);
+        assert_eq!(nan.distance(&Ru::try_from("pʲæm").unwrap()), 4);
+        assert_eq!(nan.distance(&nan), 0);
+    }
+
+    #[test]
+    fn test_na_nan() {
+        assert_eq!(
+            Ru::try_from("nʲæ nʲæn").map(|ru| format!("{}", ru)),
+            Ok("ня нян".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_maau() {
+        assert_eq!(
+            Ru::try_from("mʲæːu").map(|ru| format!("{}", ru)),
+            Ok("мяау".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_mmaau() {
+        assert_eq!(
+            Ru::try_from("mʲːæːu").map(|ru| format!("{}", ru)),
+            Ok("ммяау".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_geminates() {
+        assert_eq!(Ru::try_from("pːæ nʲːæ").map(|ru| format!("{}", ru)), Ok("ппа ння".to_owned()));
+        let options = RuOptions { geminates: Geminates::Single, ..RuOptions::default() };
+        assert_eq!(
+            Ru::try_from_ipa_with("pːæ nʲːæ mʲːæːu", options).map(|ru| format!("{}", ru)),
+            Ok("па ня мяау".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_eq_hash_ord() {
+        let na = Ru::try_from("nʲæ").unwrap();
+        let ma = Ru::try_from("mæ").unwrap();
+        assert!(na == Ru::try_from("nʲæ").unwrap());
+        assert!(na != Ru::try_from("nʲ+æ").unwrap());
+        assert!(na == na.clone().with_options(RuOptions { capitalize_words: true, ..RuOptions::default() }));
+        assert!(ma < na);
+        assert_eq!(na.as_str(), "ня");
+        let mut counts = std::collections::HashMap::new();
+        [na.clone(), ma, na].into_iter().for_each(|ru| *counts.entry(ru).or_insert(0) += 1);
+        assert_eq!(counts.get(&Ru::try_from("nʲæ").unwrap()), Some(&2));
+        assert_eq!(Ru::try_from("mæ").unwrap().into_string(), "ма");
+    }
+
+    #[test]
+    fn test_ord_agrees_with_eq() {
+        let na = Ru::try_from("nʲæ").unwrap();
+        let options = RuOptions { overrides: vec![("θ".to_owned(), "т".to_owned())], ..RuOptions::default() };
+        let overridden = Ru::try_from_ipa_with("nʲæ", options).unwrap();
+        assert_eq!(na.as_str(), overridden.as_str());
+        assert!(na != overridden);
+        assert_ne!(na.cmp(&overridden), Ordering::Equal);
+        let capitalized = na.clone().with_options(RuOptions { capitalize_words: true, ..RuOptions::default() });
+        assert!(na == capitalized);
+        assert_eq!(na.cmp(&capitalized), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_try_from_g2p() {
+        let options = RuOptions { stress_marks: StressMarks::Acute, ..RuOptions::default() };
+        assert_eq!(
+            Ru::try_from_g2p(&EspeakPhonemes("n;'a m'a"), options).map(|ru| ru.to_string()),
+            Ok("ня\u{301} ма\u{301}".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_vowel_length_as_stress() {
+        let options = RuOptions { vowel_length: VowelLength::Stress, ..RuOptions::default() };
+        assert_eq!(
+            Ru::try_from_ipa_with("mʲæːu æ.æ", options).map(|ru| format!("{}", ru)),
+            Ok("мя\u{301}у аа".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_chars() {
+        let ru = Ru::try_from("^mʲæːu").unwrap();
+        assert_eq!(ru.chars().collect::<String>(), ru.to_string());
+        assert_eq!(ru.chars().next(), Some('М'));
+        let options = RuOptions { capitalize_words: true, stress_marks: StressMarks::Acute, ..RuOptions::default() };
+        let ru = Ru::try_from_ipa_with("ˈmæ nʲo", options).unwrap();
+        assert_eq!(ru.chars().collect::<String>(), ru.as_str());
+        assert_eq!(ru.chars().filter(|letter| letter.is_uppercase()).count(), 2);
+    }
+
+    #[test]
+    fn test_overrides() {
+        let options = RuOptions {
+            overrides: vec![("θ".to_owned(), "т".to_owned()), ("nʲ".to_owned(), "нь".to_owned())],
+            ..RuOptions::default()
+        };
+        assert_eq!(
+            Ru::try_from_ipa_with("θæm nʲæ", options).map(|ru| ru.to_string()),
+            Ok("там ньа".to_owned())
+        );
+        let options = RuOptions { overrides: vec![("θ".to_owned(), "т".to_owned())], ..RuOptions::default() };
+        let ru = Ru::try_from_ipa_with("θæ", options).unwrap().with_options(RuOptions::default());
+        assert_eq!(ru.render(), Ok("та".to_owned()));
+        let other = RuOptions { overrides: vec![("ð".to_owned(), "д".to_owned())], ..RuOptions::default() };
+        assert_eq!((ru.clone() + ru.clone()).as_str(), "та та");
+        assert_eq!((ru + Ru::try_from_ipa_with("ðæ", other).unwrap()).as_str(), "та да");
+        let seq = PhonemeSeq::from(vec![Phoneme::Override { index: 0 }, Phoneme::Vowel { phoneme: Vowels::A }]);
+        let ru = Ru::from_seq(seq, RuOptions::default());
+        assert_eq!(ru.render(), Err(RenderError::MissingOverride { position: 0, index: 0 }));
+        assert_eq!(ru.as_str(), "\u{FFFD}а");
+    }
+
+    #[test]
+    fn test_words() {
+        let ru = Ru::try_from("nʲæ  ^mʲæːu").unwrap();
+        let words = ru.words().map(|word| (word.offset(), word.ipa().to_owned(), word.to_string())).collect::<Vec<_>>();
+        assert_eq!(words, vec![
+            (0, "nʲæ".to_owned(), "ня".to_owned()),
+            (7, "^mʲæːu".to_owned(), "Мяау".to_owned()),
+        ]);
+        assert_eq!(ru.words().nth(1).map(|word| word.ru().alignment()[1].clone()), Some((1..4, 0..2)));
+        assert_eq!(Ru::from_seq(ru.seq().clone(), RuOptions::default()).words().map(|word| word.offset()).max(), Some(0));
+        assert_eq!(ru.words().next().map(|word| word.capitalized().to_string()), Some("Ня".to_owned()));
+    }
+
+    #[test]
+    fn test_concat() {
+        let nya = Ru::try_from("nʲæ").unwrap();
+        let ma = Ru::try_from("mæ").unwrap();
+        assert_eq!((nya.clone() + ma.clone()).to_string(), "ня ма");
+        assert_eq!((Ru::try_from("nʲæ ").unwrap() + ma.clone()).to_string(), "ня ма");
+        assert_eq!(Ru::concat(&[nya.clone(), ma, nya.clone()]).to_string(), "ня ма ня");
+        assert_eq!(Ru::concat(&[]).to_string(), "");
+        let mut ru = nya;
+        assert_eq!(ru.push_ipa("mʲæːu"), Ok(()));
+        assert_eq!(ru.to_string(), "ня мяау");
+        assert!(ru.push_ipa("?").is_err());
+        assert_eq!(ru.ipa(), "nʲæ mʲæːu");
+        assert_eq!(ru.alignment().last(), Some(&(13..14, 11..13)));
+        let approximated = Ru::try_from("nʲʊ").unwrap();
+        let exact = Ru::try_from("nʲæn").unwrap();
+        assert!((approximated.clone() + approximated.clone()).confidence() <= approximated.confidence());
+        let joined = approximated.clone() + exact.clone();
+        assert!(joined.confidence() <= exact.confidence() && joined.confidence() < 1.0);
+        assert_eq!(joined.alignment()[3..], exact.alignment().into_iter()
+            .map(|(ipa, ru)| (ipa.start + 6..ipa.end + 6, ru.start + 5..ru.end + 5))
+            .collect::<Vec<_>>());
+        assert_eq!(ru.to_string(), "ня мяау");
+    }
+
+    #[test]
+    fn test_try_from_ipa_reported() {
+        let (ru, report) = Ru::try_from_ipa_reported("pʏ", RuOptions::default()).unwrap();
+        assert_eq!(ru.as_str(), "пу");
+        assert_eq!(report.warnings.iter().map(|warning| warning.to_string()).collect::<Vec<_>>(), vec![
+            "ʏ rendered as у at byte 1".to_owned(),
+        ]);
+    }
+
+    #[test]
+    fn test_try_from_lossy() {
+        assert_eq!(
+            Ru::try_from_lossy("nʲæ?mæ ʘ", RuOptions::default(), "?"),
+            ("ня?ма ?".to_owned(), vec![5..6, 10..12])
+        );
+        assert_eq!(Ru::try_from_lossy("mʲæːu", RuOptions::default(), REPLACEMENT), ("мяау".to_owned(), vec![]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_write_to() {
+        let ru = Ru::try_from("nʲæ mʲæːu").unwrap();
+        let mut bytes = Vec::new();
+        assert!(ru.write_to(&mut bytes).is_ok());
+        assert_eq!(String::from_utf8(bytes), Ok("ня мяау".to_owned()));
+    }
+
+    #[test]
+    fn test_write_to_fmt() {
+        let ru = Ru::try_from("nʲæ mʲæːu").unwrap();
+        let mut text = "> ".to_owned();
+        assert_eq!(ru.write_to_fmt(&mut text), Ok(()));
+        assert_eq!(text, "> ня мяау");
+        struct Pieces(Vec<String>);
+        impl fmt::Write for Pieces {
+            fn write_str(&mut self, piece: &str) -> fmt::Result {
+                self.0.push(piece.to_owned());
+                Ok(())
+            }
+        }
+        let mut pieces = Pieces(Vec::new());
+        assert_eq!(ru.write_to_fmt(&mut pieces), Ok(()));
+        assert_eq!(pieces.0, ["н", "я", " ", "м", "я", "а", "у"]);
+    }
+
+    #[test]
+    fn test_phonemes() {
+        let ru = Ru::try_from("nʲˈæ mæ").unwrap();
+        assert_eq!(ru.phonemes(), &[
+            Phoneme::Consonant { phoneme: Consonants::N, is_palatalized: true },
+            Phoneme::Vowel { phoneme: Vowels::A },
+            Phoneme::Stress,
+            Phoneme::Probel { separator: Separator::Space },
+            Phoneme::Consonant { phoneme: Consonants::M, is_palatalized: false },
+            Phoneme::Vowel { phoneme: Vowels::A },
+        ]);
+    }
+
+    #[test]
+    fn test_soft_sign_before_iotated_vowel() {
+        assert_eq!(
+            Ru::try_from("pʲjenæ pʲjæ pʲju pʲji pʲjo pjæ").map(|ru| format!("{}", ru)),
+            Ok("пьена пья пью пьи пьё пъя".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_practical_options() {
+        assert_eq!(
+            Ru::try_from_ipa_with("nʲˈæːmmæ ˈe.ɑ", RuOptions::practical()).map(|ru| format!("{}", ru)),
+            Ok("нямма эа".to_owned())
+        );
+        assert_eq!(
+            Ru::try_from_ipa_with("hænnæ wæn", RuOptions::practical()).map(|ru| format!("{}", ru)),
+            Ok("ханна ван".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_vowel_length_marks() {
+        let cases = [
+            (VowelLength::Macron, "мя\u{304}у"),
+            (VowelLength::Colon,  "мя:у"),
+            (VowelLength::Drop,   "мяу"),
+        ];
+        for (vowel_length, expected) in cases {
+            let options = RuOptions { vowel_length, ..RuOptions::default() };
+            assert_eq!(Ru::try_from_ipa_with("mʲæːu", options).map(|ru| format!("{}", ru)), Ok(expected.to_owned()));
+        }
+    }
+
+    #[test]
+    fn test_capitalize_words() {
+        assert_eq!(Ru::try_from("^nʲæ mæ ^").map(|ru| format!("{}", ru)), Ok("Ня ма ".to_owned()));
+        let options = RuOptions { capitalize_words: true, ..RuOptions::default() };
+        assert_eq!(
+            Ru::try_from_ipa_with("nʲæ  jæ+mæ", options).map(|ru| format!("{}", ru)),
+            Ok("Ня  Яма".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_hyphenation() {
+        let options = RuOptions { hyphenation: Hyphenation::Marked, ..RuOptions::default() };
+        assert_eq!(
+            Ru::try_from_ipa_with("nʲæ.nʲæn .mæ. mæmæ", options).map(|ru| format!("{}", ru)),
+            Ok("ня-нян ма мама".to_owned())
+        );
+        let options = RuOptions { hyphenation: Hyphenation::Computed, ..RuOptions::default() };
+        assert_eq!(
+            Ru::try_from_ipa_with("mæmæ pæmpæ æjæ mæːu nʲæ.pmæ", options).map(|ru| format!("{}", ru)),
+            Ok("ма-ма пам-па а-я маау ня-пма".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_lookalike_input() {
+        assert_eq!(
+            Ru::try_from("m\u{2b9}æ:u").map(|ru| format!("{}", ru)),
+            Ru::try_from("mʲæːu").map(|ru| format!("{}", ru))
+        );
+    }
+
+    #[test]
+    fn test_dental_and_unreleased() {
+        assert_eq!(
+            Ru::try_from("n\u{32a}ʲæp\u{31a}").map(|ru| format!("{}", ru)),
+            Ok("няп".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_separators() {
+        assert_eq!(
+            Ru::try_from("nʲæ\tnʲæn\r\nnʲæ\u{a0}nʲæ\nnʲæ").map(|ru| format!("{}", ru)),
+            Ok("ня\tнян\r\nня\u{a0}ня\nня".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_nfc_output() {
+        let ru = Ru::try_from("nʲoj").unwrap();
+        assert_eq!(format!("{}", ru), "нёй");
+        assert_eq!(ru.render(), Ok("нёй".to_owned()));
+        assert!(unicode_normalization::is_nfc(&ru.to_string()));
+    }
+
+    #[test]
+    fn test_nfd_output() {
+        let ru = Ru::try_from("nʲoj").unwrap().with_options(RuOptions {
+            normalization: NormalizationForm::Nfd,
+            ..RuOptions::default()
+        });
+        assert_eq!(format!("{}", ru), "не\u{308}и\u{306}");
+        assert_eq!(ru.render(), Ok("не\u{308}и\u{306}".to_owned()));
+    }
+
+    #[test]
+    fn test_release_modifiers() {
+        assert!(Ru::try_from("nʲæⁿ").is_err());
+        let drop = RuOptions { release_modifiers: ReleaseModifiers::Drop, ..RuOptions::default() };
+        assert_eq!(
+            Ru::try_from_ipa_with("pᵊnʲæⁿ mˡ", drop).map(|ru| format!("{}", ru)),
+            Ok("пня м".to_owned())
+        );
+        let reduced = RuOptions { release_modifiers: ReleaseModifiers::ReducedVowel, ..RuOptions::default() };
+        assert_eq!(
+            Ru::try_from_ipa_with("pᵊnʲæ nʲᵊ", reduced).map(|ru| format!("{}", ru)),
+            Ok("пъня нь".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_render_audited() {
+        let mut events = Vec::new();
+        assert_eq!(
+            Ru::try_from("pjæ nʲ").map(|ru| ru.render_audited(&mut events)),
+            Ok(Ok("пъя нь".to_owned()))
+        );
+        assert_eq!(events, vec![
+            AuditEvent { position: 1, span: 1..2, rule: AuditRule::HardSign, output: "ъ", alternatives: vec!["ь"] },
+            AuditEvent { position: 4, span: 5..8, rule: AuditRule::SoftSign, output: "нь", alternatives: vec!["н"] },
+        ]);
+    }
+
+    #[test]
+    fn test_stress_marks() {
+        assert_eq!(Ru::try_from("mæˈnʲæ").map(|ru| format!("{}", ru)), Ok("маня".to_owned()));
+        let options = RuOptions { stress_marks: StressMarks::Acute, ..RuOptions::default() };
+        assert_eq!(
+            Ru::try_from_ipa_with("mæˈnʲæ ˌmæˈmæ", options).map(|ru| format!("{}", ru)),
+            Ok("маня\u{301} мама\u{301}".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_vowel_reduction() {
+        let options = RuOptions { vowel_reduction: VowelReduction::Akanye, ..RuOptions::default() };
+        assert_eq!(
+            Ru::try_from_ipa_with("ˈmonʲe moˈnʲe monʲe", options).map(|ru| format!("{}", ru)),
+            Ok("мони мане моне".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_hiatus() {
+        assert_eq!(Ru::try_from("æ.u æu").map(|ru| format!("{}", ru)), Ok("ау ау".to_owned()));
+        let options = RuOptions { hiatus: Hiatus::InsertJot, ..RuOptions::default() };
+        assert_eq!(
+            Ru::try_from_ipa_with("æ.u æu mæ.nʲæ", options).map(|ru| format!("{}", ru)),
+            Ok("айу ау маня".to_owned())
+        );
+        let options = RuOptions { hiatus: Hiatus::Hyphen, ..RuOptions::default() };
+        assert_eq!(Ru::try_from_ipa_with("æ.u", options).map(|ru| format!("{}", ru)), Ok("а-у".to_owned()));
+    }
+
+    #[test]
+    fn test_initial_e() {
+        assert_eq!(Ru::try_from("ep pep").map(|ru| format!("{}", ru)), Ok("эп пэп".to_owned()));
+        let options = RuOptions { initial_e: InitialE::Ye, ..RuOptions::default() };
+        assert_eq!(Ru::try_from_ipa_with("ep pep", options).map(|ru| format!("{}", ru)), Ok("еп пэп".to_owned()));
+    }
+
+    #[test]
+    fn test_morpheme_boundary() {
+        assert_eq!(
+            Ru::try_from("p+jæ pjæ nʲ‿æ").map(|ru| format!("{}", ru)),
+            Ok("пъя пъя ня".to_owned())
+        );
+        let options = RuOptions { jot_after_consonant: JotAfterConsonant::MorphemeBoundary, ..RuOptions::default() };
+        assert_eq!(
+            Ru::try_from_ipa_with("p+jæ pjæ", options).map(|ru| format!("{}", ru)),
+            Ok("пъя пья".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            Ru::try_from("mʲæːu").map(|ru| ru.render()),
+            Ok(Ok("мяау".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_try_from_ipa() {
+        assert_eq!(
+            Ru::try_from_ipa("nʲæ nʲæn").map(|ru| format!("{}", ru)),
+            Ok("ня нян".to_owned())
+        );
+        assert!(Ru::try_from("nʲæ?").is_err());
+        assert_eq!(
+            Ru::try_from_ipa("nʲæ ʘ̃n").err(),
+            Some(Error::UnsupportedSymbol { offset: 6, char_offset: 4, symbol: "ʘ̃".to_owned() })
+        );
+        assert_eq!(
+            Ru::try_from_ipa("mæʲ").err(),
+            Some(Error::UnsupportedCombination { offset: 1, char_offset: 1, symbols: "æʲ".to_owned() })
         );
+        let options = RuOptions { stress_marks: StressMarks::Acute, vowel_length: VowelLength::Stress, ..RuOptions::default() };
+        assert!(matches!(Ru::try_from_ipa_with("nʲæ", options), Err(Error::OptionConflict { first: _, second: _ })));
     }
 }
@@ -0,0 +1,312 @@
+use core::mem;
+
+use alloc::vec::Vec;
+
+use crate::options::{
+    Affricates, ClusterRules, FinalDevoicing, Geminates, HushingSoftSign, Hyphenation, InitialE, JotAfterConsonant,
+    JotRules, Orthography, QRules, RuOptions, TseVowel, VowelLength, VowelReduction, YoPolicy,
+};
+use crate::ru::PhonemeSeq;
+
+pub trait RuRule {
+    fn apply(&self, seq: &mut PhonemeSeq);
+}
+
+impl<F: Fn(&mut PhonemeSeq)> RuRule for F {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        self(seq)
+    }
+}
+
+impl RuRule for Affricates {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        *seq = mem::take(seq).decompose_affricates(*self);
+    }
+}
+
+impl RuRule for ClusterRules {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        *seq = mem::take(seq).repair_clusters(self);
+    }
+}
+
+impl RuRule for Hyphenation {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        *seq = mem::take(seq).syllabify(*self);
+    }
+}
+
+impl RuRule for Geminates {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        seq.spell_geminates(*self);
+    }
+}
+
+impl RuRule for FinalDevoicing {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        seq.restore_voicing(*self);
+    }
+}
+
+impl RuRule for VowelLength {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        seq.spell_vowel_length(*self);
+    }
+}
+
+impl RuRule for JotAfterConsonant {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        seq.spell_jot_after_consonant(*self);
+    }
+}
+
+impl RuRule for JotRules {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        seq.spell_jots(self);
+    }
+}
+
+impl RuRule for Orthography {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        seq.spell_after_hushing(*self);
+    }
+}
+
+impl RuRule for TseVowel {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        seq.spell_after_tse(*self);
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Jers;
+
+impl RuRule for Jers {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        seq.spell_jers(false);
+    }
+}
+
+impl RuRule for HushingSoftSign {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        if *self == HushingSoftSign::Grammatical {
+            seq.spell_jers(true);
+        }
+    }
+}
+
+impl RuRule for YoPolicy {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        seq.spell_yo(*self);
+    }
+}
+
+impl RuRule for InitialE {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        seq.spell_initial_e(*self);
+    }
+}
+
+impl RuRule for QRules {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        seq.spell_q(self);
+    }
+}
+
+impl RuRule for VowelReduction {
+    fn apply(&self, seq: &mut PhonemeSeq) {
+        seq.reduce_vowels(*self);
+    }
+}
+
+pub(crate) fn builtin(options: &RuOptions) -> [&dyn RuRule; 3] {
+    [&options.affricates, &options.cluster_rules, &options.hyphenation]
+}
+
+pub(crate) fn orthography(options: &RuOptions) -> Vec<&dyn RuRule> {
+    let tse_vowel = (options.orthography == Orthography::Standard).then_some(&options.tse_vowel as &dyn RuRule);
+    [
+        &options.geminates as &dyn RuRule,
+        &options.final_devoicing,
+        &options.vowel_length,
+        &options.jot_after_consonant,
+        &options.jot_rules,
+        &options.orthography,
+    ]
+        .into_iter()
+        .chain(tse_vowel)
+        .chain([
+            &Jers as &dyn RuRule,
+            &options.hushing_soft_sign,
+            &options.yo_policy,
+            &options.initial_e,
+            &options.q_rules,
+            &options.vowel_reduction,
+        ])
+        .collect()
+}
+
+#[cfg(test)]
+mod rule_tests {
+    use super::*;
+    use crate::options::{ClusterStrategy, JotSpelling, QSpelling};
+    use crate::ru::{Consonants, PalatalizedOnlyConsonants, Phoneme, Ru, Vowels};
+
+    #[test]
+    fn test_custom_rules() {
+        let okanye = |seq: &mut PhonemeSeq| seq.phonemes_mut().iter_mut().for_each(|phoneme| {
+            if *phoneme == (Phoneme::Vowel { phoneme: Vowels::A }) {
+                *phoneme = Phoneme::Vowel { phoneme: Vowels::O };
+            }
+        });
+        let drop_last = |seq: &mut PhonemeSeq| {
+            seq.phonemes_mut().pop();
+        };
+        let rules: [&dyn RuRule; 2] = [&okanye, &drop_last];
+        assert_eq!(
+            Ru::try_from_ipa_with_rules("mæmæn", RuOptions::default(), &rules).map(|ru| ru.to_string()),
+            Ok("момо".to_owned())
+        );
+    }
+
+    fn render(phonemes: &[Phoneme], rule: &dyn RuRule, options: RuOptions) -> String {
+        let mut seq = PhonemeSeq::from(phonemes.to_vec());
+        rule.apply(&mut seq);
+        Ru::from_seq(seq, options).to_string()
+    }
+
+    fn consonant(phoneme: Consonants, is_palatalized: bool) -> Phoneme {
+        Phoneme::Consonant { phoneme, is_palatalized }
+    }
+
+    const A: Phoneme = Phoneme::Vowel { phoneme: Vowels::A };
+    const E: Phoneme = Phoneme::Vowel { phoneme: Vowels::E };
+    const I: Phoneme = Phoneme::Vowel { phoneme: Vowels::I };
+    const O: Phoneme = Phoneme::Vowel { phoneme: Vowels::O };
+    const J: Phoneme = Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::J };
+    const Q: Phoneme = Phoneme::PalatalizedOnlyConsonant { phoneme: PalatalizedOnlyConsonants::Q };
+
+    #[test]
+    fn test_builtin_rules() {
+        let options = RuOptions { hyphenation: Hyphenation::Computed, ..RuOptions::default() };
+        let mama = [consonant(Consonants::M, false), A, consonant(Consonants::M, false), A];
+        assert_eq!(render(&mama, &Hyphenation::Computed, options.clone()), "ма-ма");
+        assert_eq!(render(&mama, &Hyphenation::None, options), "мама");
+        let dzhaz = [consonant(Consonants::DX, false), A, consonant(Consonants::Z, false)];
+        let mut seq = PhonemeSeq::from(dzhaz.to_vec());
+        Affricates::Decomposed.apply(&mut seq);
+        assert_eq!(seq.phonemes()[..2], [consonant(Consonants::D, false), consonant(Consonants::X, false)]);
+        assert_eq!(Ru::from_seq(seq, RuOptions::default()).to_string(), "джаз");
+        let strast = [consonant(Consonants::S, false), consonant(Consonants::T, false), consonant(Consonants::R, false), A];
+        let rules = ClusterRules { max_len: 2, strategy: ClusterStrategy::Hyphen };
+        assert_eq!(render(&strast, &rules, RuOptions::default()), "ст-ра");
+    }
+
+    #[test]
+    fn test_geminates() {
+        let tta = [consonant(Consonants::T, false), consonant(Consonants::T, false), A];
+        assert_eq!(render(&tta, &Geminates::Single, RuOptions::default()), "та");
+        assert_eq!(render(&tta, &Geminates::Double, RuOptions::default()), "тта");
+    }
+
+    #[test]
+    fn test_final_devoicing() {
+        let kot = [consonant(Consonants::K, false), O, consonant(Consonants::T, false)];
+        assert_eq!(render(&kot, &FinalDevoicing::Restore, RuOptions::default()), "код");
+        assert_eq!(render(&kot, &FinalDevoicing::Literal, RuOptions::default()), "кот");
+        let mat = [consonant(Consonants::M, false), A, consonant(Consonants::T, true)];
+        assert_eq!(render(&mat, &FinalDevoicing::Restore, RuOptions::default()), "мадь");
+    }
+
+    #[test]
+    fn test_vowel_length() {
+        let maa = [consonant(Consonants::M, false), A, A];
+        assert_eq!(render(&maa, &VowelLength::Colon, RuOptions::default()), "ма:");
+        assert_eq!(render(&maa, &VowelLength::Drop, RuOptions::default()), "ма");
+        assert_eq!(render(&maa, &VowelLength::Double, RuOptions::default()), "маа");
+    }
+
+    #[test]
+    fn test_jot_after_consonant() {
+        let sje = [consonant(Consonants::S, false), J, E];
+        assert_eq!(render(&sje, &JotAfterConsonant::SoftSign, RuOptions::default()), "сье");
+        assert_eq!(render(&sje, &JotAfterConsonant::HardSign, RuOptions::default()), "съе");
+    }
+
+    #[test]
+    fn test_jot_rules() {
+        let jork = [J, O, consonant(Consonants::R, false), consonant(Consonants::K, false)];
+        let rules = JotRules { onset: JotSpelling::ShortI, ..JotRules::default() };
+        assert_eq!(render(&jork, &rules, RuOptions::default()), "йорк");
+        assert_eq!(render(&jork, &JotRules::default(), RuOptions::default()), "ёрк");
+        let maj = [consonant(Consonants::M, false), A, J];
+        let rules = JotRules { coda: JotSpelling::I, ..JotRules::default() };
+        assert_eq!(render(&maj, &rules, RuOptions::default()), "маи");
+    }
+
+    #[test]
+    fn test_orthography() {
+        let phonetic = RuOptions { orthography: Orthography::Phonetic, ..RuOptions::default() };
+        let zhi = [consonant(Consonants::X, false), I];
+        assert_eq!(render(&zhi, &Orthography::Standard, phonetic.clone()), "жи");
+        assert_eq!(render(&zhi, &Orthography::Phonetic, phonetic.clone()), "жы");
+        assert_eq!(render(&[Q, O], &Orthography::Standard, phonetic.clone()), "чё");
+        assert_eq!(render(&[Q, O], &Orthography::Phonetic, phonetic), "чо");
+    }
+
+    #[test]
+    fn test_tse_vowel() {
+        let phonetic = RuOptions { orthography: Orthography::Phonetic, ..RuOptions::default() };
+        let tsy = [consonant(Consonants::C, false), I];
+        assert_eq!(render(&tsy, &TseVowel::I, phonetic.clone()), "ци");
+        assert_eq!(render(&tsy, &TseVowel::Y, phonetic.clone()), "цы");
+        assert_eq!(orthography(&phonetic).len() + 1, orthography(&RuOptions::default()).len());
+    }
+
+    #[test]
+    fn test_jers() {
+        let mut seq = PhonemeSeq::from(vec![consonant(Consonants::M, false), A, consonant(Consonants::T, true)]);
+        Jers.apply(&mut seq);
+        assert_eq!(seq.spelling(2), Some("ть"));
+        assert_eq!(seq.spelling(0), None);
+    }
+
+    #[test]
+    fn test_hushing_soft_sign() {
+        let rozh = [consonant(Consonants::R, false), O, consonant(Consonants::X, true)];
+        assert_eq!(render(&rozh, &HushingSoftSign::Grammatical, RuOptions::default()), "рожь");
+        assert_eq!(render(&rozh, &HushingSoftSign::Suppress, RuOptions::default()), "рож");
+    }
+
+    #[test]
+    fn test_yo_policy() {
+        let njos = [consonant(Consonants::N, true), O, consonant(Consonants::S, false)];
+        assert_eq!(render(&njos, &YoPolicy::Ye, RuOptions::default()), "нес");
+        assert_eq!(render(&njos, &YoPolicy::Yo, RuOptions::default()), "нёс");
+    }
+
+    #[test]
+    fn test_initial_e() {
+        let em = [E, consonant(Consonants::M, false)];
+        assert_eq!(render(&em, &InitialE::Ye, RuOptions::default()), "ем");
+        assert_eq!(render(&em, &InitialE::E, RuOptions::default()), "эм");
+    }
+
+    #[test]
+    fn test_q_rules() {
+        let noch = [consonant(Consonants::N, false), O, Q];
+        let rules = QRules { word_final: QSpelling::ChSoftSign, ..QRules::default() };
+        assert_eq!(render(&noch, &rules, RuOptions::default()), "ночь");
+        assert_eq!(render(&noch, &QRules::default(), RuOptions::default()), "ноч");
+    }
+
+    #[test]
+    fn test_vowel_reduction() {
+        let moloko = [
+            consonant(Consonants::M, false), O, consonant(Consonants::L, false), O, consonant(Consonants::K, false), O,
+            Phoneme::Stress,
+        ];
+        assert_eq!(render(&moloko, &VowelReduction::Akanye, RuOptions::default()), "малако");
+        assert_eq!(render(&moloko, &VowelReduction::None, RuOptions::default()), "молоко");
+    }
+}
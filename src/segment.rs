@@ -0,0 +1,67 @@
+use icu_segmenter::{SentenceSegmenter, WordSegmenter};
+
+use crate::error::Error;
+use crate::options::RuOptions;
+use crate::words::RuWord;
+
+pub struct IcuSegmenter {
+    words: WordSegmenter,
+    sentences: SentenceSegmenter,
+    options: RuOptions,
+}
+
+impl IcuSegmenter {
+    pub fn new() -> Self {
+        Self::with_options(RuOptions::default())
+    }
+
+    pub fn with_options(options: RuOptions) -> Self {
+        Self { words: WordSegmenter::new_auto(), sentences: SentenceSegmenter::new(), options }
+    }
+
+    pub fn sentences<'a>(&self, input: &'a str) -> Vec<(usize, &'a str)> {
+        let breaks = self.sentences.segment_str(input).collect::<Vec<_>>();
+        breaks.windows(2)
+            .map(|bounds| (bounds[0], &input[bounds[0]..bounds[1]]))
+            .collect()
+    }
+
+    pub fn words(&self, input: &str) -> Vec<Result<RuWord, Error>> {
+        let mut words = Vec::new();
+        let mut breaks = self.words.segment_str(input);
+        let mut start = breaks.next().unwrap_or_default();
+        while let Some(end) = breaks.next() {
+            if breaks.is_word_like() {
+                words.push(RuWord::convert(start, &input[start..end], self.options.clone()));
+            }
+            start = end;
+        }
+        words
+    }
+}
+
+impl Default for IcuSegmenter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod segment_tests {
+    use super::*;
+
+    #[test]
+    fn test_words() {
+        let words = IcuSegmenter::new()
+            .words("nʲæ, (mæ)!")
+            .into_iter()
+            .map(|word| word.map(|word| (word.offset(), word.to_string())))
+            .collect::<Vec<_>>();
+        assert_eq!(words, vec![Ok((0, "ня".to_owned())), Ok((8, "ма".to_owned()))]);
+    }
+
+    #[test]
+    fn test_sentences() {
+        assert_eq!(IcuSegmenter::new().sentences("nʲæ! mæ?"), vec![(0, "nʲæ! "), (7, "mæ?")]);
+    }
+}
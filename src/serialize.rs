@@ -0,0 +1,79 @@
+use alloc::{string::String, vec::Vec};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::options::RuOptions;
+use crate::ru::{Phoneme, Ru};
+
+#[derive(Serialize)]
+struct RuRef<'a> {
+    phonemes: &'a [Phoneme],
+    overrides: &'a [(String, String)],
+    options: &'a RuOptions,
+}
+
+#[derive(Deserialize)]
+struct RuOwned {
+    phonemes: Vec<Phoneme>,
+    #[serde(default)]
+    overrides: Vec<(String, String)>,
+    #[serde(default)]
+    options: RuOptions,
+}
+
+impl Serialize for Ru {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RuRef { phonemes: self.phonemes(), overrides: self.seq().overrides(), options: self.options() }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Ru {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RuOwned { phonemes, overrides, options } = RuOwned::deserialize(deserializer)?;
+        Ok(Ru::from_phonemes(phonemes, overrides, options))
+    }
+}
+
+pub fn text<S: Serializer>(ru: &Ru, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(ru.as_str())
+}
+
+#[cfg(test)]
+mod serialize_tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Row {
+        #[serde(serialize_with = "text")]
+        ru: Ru,
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let ru = Ru::try_from("nʲæ").unwrap();
+        let json = serde_json::to_string(&ru).unwrap();
+        assert!(json.starts_with(
+            r#"{"phonemes":[{"Consonant":{"phoneme":"N","is_palatalized":true}},{"Vowel":{"phoneme":"A"}}],"#
+        ));
+        assert!(serde_json::from_str::<Ru>(&json).unwrap() == ru);
+    }
+
+    #[test]
+    fn test_bincode_round_trip() {
+        let ru = Ru::try_from("nʲæ mʲæn").unwrap();
+        let bytes = bincode::serialize(&ru).unwrap();
+        assert!(bincode::deserialize::<Ru>(&bytes).unwrap() == ru);
+    }
+
+    #[test]
+    fn test_text() {
+        let row = Row { ru: Ru::try_from("mʲæːu").unwrap() };
+        assert_eq!(serde_json::to_string(&row).unwrap(), r#"{"ru":"мяау"}"#);
+    }
+
+    #[test]
+    fn test_partial_options() {
+        let ru = serde_json::from_str::<Ru>(r#"{"phonemes":[{"Vowel":{"phoneme":"O"}}],"options":{"yo_policy":"ye"}}"#);
+        assert_eq!(ru.map(|ru| ru.to_string()).ok(), Some("о".to_owned()));
+    }
+}
@@ -0,0 +1,111 @@
+use core::str;
+use alloc::{string::{String, ToString}, vec::Vec};
+
+use crate::error::Error;
+use crate::input::{self, Token};
+use crate::options::RuOptions;
+use crate::ru::Ru;
+
+#[derive(Clone, Debug, Default)]
+pub struct RuStream {
+    options: RuOptions,
+    pending: Vec<u8>,
+    consumed: usize,
+}
+
+impl RuStream {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_options(options: RuOptions) -> Self {
+        Self { options, ..Self::default() }
+    }
+
+    pub fn push(&mut self, chunk: &[u8]) -> Result<String, Error> {
+        self.pending.extend_from_slice(chunk);
+        let valid_len = match str::from_utf8(&self.pending) {
+            Ok(text) => text.len(),
+            Err(error) => match error.error_len() {
+                Some(_) => return Err(Error::InvalidUtf8 { offset: self.consumed + error.valid_up_to() }),
+                None => error.valid_up_to(),
+            },
+        };
+        let complete_len = last_boundary(str::from_utf8(&self.pending[..valid_len]).unwrap_or_default(), &self.options);
+        self.convert(complete_len)
+    }
+
+    pub fn finish(mut self) -> Result<String, Error> {
+        match str::from_utf8(&self.pending) {
+            Ok(text) => {
+                let len = text.len();
+                self.convert(len)
+            },
+            Err(error) => Err(Error::InvalidUtf8 { offset: self.consumed + error.valid_up_to() }),
+        }
+    }
+
+    fn convert(&mut self, len: usize) -> Result<String, Error> {
+        if len == 0 {
+            return Ok(String::new());
+        }
+        let complete = str::from_utf8(&self.pending[..len]).unwrap_or_default();
+        let rendered = Ru::try_from_ipa_with(complete, self.options.clone())?.to_string();
+        self.pending.drain(..len);
+        self.consumed += len;
+        Ok(rendered)
+    }
+}
+
+fn last_boundary(text: &str, options: &RuOptions) -> usize {
+    input::tokenize(text, options)
+        .scan(0, |position, token| {
+            *position += token.as_str().len();
+            Some((*position, token))
+        })
+        .filter(|(_, token)| matches!(token, Token::Separator(_)))
+        .map(|(position, _)| position)
+        .last()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod stream_tests {
+    use super::*;
+
+    #[test]
+    fn test_every_split() {
+        let ipa = "nʲæ mʲːæːu\r\nnʲæn".as_bytes();
+        let expected = Ru::try_from_ipa("nʲæ mʲːæːu\r\nnʲæn").unwrap().to_string();
+        (0..=ipa.len()).for_each(|split| {
+            let mut stream = RuStream::new();
+            let mut output = stream.push(&ipa[..split]).unwrap();
+            output.push_str(&stream.push(&ipa[split..]).unwrap());
+            output.push_str(&stream.finish().unwrap());
+            assert_eq!(output, expected, "split at {}", split);
+        });
+    }
+
+    #[test]
+    fn test_byte_by_byte() {
+        let mut stream = RuStream::new();
+        let mut output = String::new();
+        "nʲæ nʲæn".as_bytes().iter().for_each(|byte| output.push_str(&stream.push(&[*byte]).unwrap()));
+        output.push_str(&stream.finish().unwrap());
+        assert_eq!(output, "ня нян");
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let mut stream = RuStream::new();
+        assert_eq!(stream.push("nʲæ ".as_bytes()), Ok("ня ".to_owned()));
+        assert_eq!(stream.push(&[0x6e, 0xff]), Err(Error::InvalidUtf8 { offset: 7 }));
+    }
+
+    #[test]
+    fn test_truncated_utf8() {
+        let mut stream = RuStream::new();
+        assert_eq!(stream.push(&"nʲæ".as_bytes()[..4]), Ok(String::new()));
+        assert_eq!(stream.finish(), Err(Error::InvalidUtf8 { offset: 3 }));
+    }
+}
@@ -0,0 +1,101 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::input::{Release, Stress, Token};
+use crate::options::{ReleaseModifiers, RuOptions};
+use crate::ru::Ru;
+
+const IPA_SEGMENTS: &[&str] = &[
+    "i", "y", "ɨ", "ʉ", "ɯ", "u", "ɪ", "ʏ", "ʊ", "e", "ø", "ɘ", "ɵ", "ɤ", "o", "ə", "ɛ", "œ", "ɜ", "ɞ",
+    "ʌ", "ɔ", "æ", "ɐ", "a", "ɶ", "ɑ", "ɒ",
+    "p", "b", "t", "d", "ʈ", "ɖ", "c", "ɟ", "k", "ɡ", "q", "ɢ", "ʔ", "m", "ɱ", "n", "ɳ", "ɲ", "ŋ", "ɴ",
+    "ʙ", "r", "ʀ", "ⱱ", "ɾ", "ɽ", "ɸ", "β", "f", "v", "θ", "ð", "s", "z", "ʃ", "ʒ", "ʂ", "ʐ", "ç", "ʝ",
+    "x", "ɣ", "χ", "ʁ", "ħ", "ʕ", "h", "ɦ", "ɬ", "ɮ", "ʋ", "ɹ", "ɻ", "j", "ɰ", "l", "ɭ", "ʎ", "ʟ", "w",
+    "ʍ", "ɥ",
+];
+
+const IPA_MODIFIERS: &[&str] = &["ʲ", "ː"];
+
+const RELEASES: &[Release] = &[Release::Schwa, Release::Nasal, Release::Lateral];
+
+const SUPRASEGMENTALS: &[Token<'static>] = &[
+    Token::Stress(Stress::Primary),
+    Token::Stress(Stress::Secondary),
+    Token::SyllableBoundary,
+    Token::MorphemeBoundary,
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+    Vowel,
+    Consonant,
+    Modifier,
+    Suprasegmental,
+    Override,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SupportedSymbol {
+    pub ipa: String,
+    pub kind: SymbolKind,
+    pub ru: Option<String>,
+}
+
+impl SupportedSymbol {
+    fn new(ipa: &str, kind: SymbolKind, ru: Option<String>) -> Self {
+        Self { ipa: String::from(ipa), kind, ru }
+    }
+}
+
+pub fn supported_symbols() -> Vec<SupportedSymbol> {
+    supported_symbols_with(&RuOptions::default())
+}
+
+pub fn supported_symbols_with(options: &RuOptions) -> Vec<SupportedSymbol> {
+    let segments = IPA_SEGMENTS.iter().filter_map(|ipa| {
+        let kind = match *ipa_sounds::Ipa::try_from(*ipa).ok()?.iter().next()? {
+            ipa_sounds::Sound::Vowel { phoneme: _, is_long: _ } => SymbolKind::Vowel,
+            ipa_sounds::Sound::Consonant { phoneme: _, is_long: _, is_palatalized: _ } => SymbolKind::Consonant,
+            ipa_sounds::Sound::Space => return None,
+        };
+        let ru = Ru::try_from_ipa_with(ipa, options.clone()).ok()?.into_string();
+        Some(SupportedSymbol::new(ipa, kind, Some(ru)))
+    });
+    let releases = match options.release_modifiers {
+        ReleaseModifiers::Reject => &[][..],
+        ReleaseModifiers::Drop | ReleaseModifiers::ReducedVowel => RELEASES,
+    };
+    let modifiers = IPA_MODIFIERS.iter().copied().chain(releases.iter().map(|release| release.as_str()));
+    segments
+        .chain(modifiers.map(|ipa| SupportedSymbol::new(ipa, SymbolKind::Modifier, None)))
+        .chain(SUPRASEGMENTALS.iter().map(|token| SupportedSymbol::new(token.as_str(), SymbolKind::Suprasegmental, None)))
+        .chain(options.overrides.iter().map(|(ipa, ru)| SupportedSymbol::new(ipa, SymbolKind::Override, Some(ru.clone()))))
+        .collect()
+}
+
+#[cfg(test)]
+mod symbols_tests {
+    use super::*;
+    use alloc::borrow::ToOwned;
+
+    #[test]
+    fn test_supported_symbols() {
+        let symbols = supported_symbols();
+        assert!(symbols.contains(&SupportedSymbol::new("æ", SymbolKind::Vowel, Some("а".to_owned()))));
+        assert!(symbols.contains(&SupportedSymbol::new("n", SymbolKind::Consonant, Some("н".to_owned()))));
+        assert!(symbols.contains(&SupportedSymbol::new("ʲ", SymbolKind::Modifier, None)));
+        assert!(symbols.contains(&SupportedSymbol::new("ˈ", SymbolKind::Suprasegmental, None)));
+        assert!(!symbols.iter().any(|symbol| symbol.ipa == "ʘ" || symbol.ipa == "ᵊ"));
+    }
+
+    #[test]
+    fn test_supported_symbols_with() {
+        let options = RuOptions {
+            release_modifiers: ReleaseModifiers::Drop,
+            overrides: vec![("θ".to_owned(), "т".to_owned())],
+            ..RuOptions::default()
+        };
+        let symbols = supported_symbols_with(&options);
+        assert!(symbols.contains(&SupportedSymbol::new("ᵊ", SymbolKind::Modifier, None)));
+        assert_eq!(symbols.last(), Some(&SupportedSymbol::new("θ", SymbolKind::Override, Some("т".to_owned()))));
+    }
+}
@@ -0,0 +1,107 @@
+use std::panic;
+
+use crate::ru::Ru;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RegressionCase {
+    pub ipa: &'static str,
+    pub expected: Option<&'static str>,
+}
+
+const REGRESSION_CORPUS: &[RegressionCase] = &[
+    RegressionCase { ipa: "nʲæ",      expected: Some("ня") },
+    RegressionCase { ipa: "nʲæ nʲæn", expected: Some("ня нян") },
+    RegressionCase { ipa: "mʲæːu",    expected: Some("мяау") },
+    RegressionCase { ipa: "mʲːæːu",   expected: Some("ммяау") },
+    RegressionCase { ipa: "",         expected: Some("") },
+    RegressionCase { ipa: " ",        expected: Some(" ") },
+    RegressionCase { ipa: "   ",      expected: Some("   ") },
+    RegressionCase { ipa: "j",        expected: Some("й") },
+    RegressionCase { ipa: "jj",       expected: Some("йй") },
+    RegressionCase { ipa: "nj",       expected: Some("нй") },
+    RegressionCase { ipa: "æ nʲ",     expected: Some("а нь") },
+    RegressionCase { ipa: " nʲæ ",    expected: Some(" ня ") },
+];
+
+pub fn regression_corpus() -> &'static [RegressionCase] {
+    REGRESSION_CORPUS
+}
+
+impl RegressionCase {
+    pub fn check(&self) -> bool {
+        let actual = Ru::try_from(self.ipa).map(|ru| ru.to_string());
+        match self.expected {
+            Some(expected) => actual.as_deref() == Ok(expected),
+            None => true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SelfTestReport {
+    pub checks: usize,
+    pub failures: Vec<String>,
+}
+
+impl SelfTestReport {
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    fn check(&mut self, is_ok: bool, failure: impl FnOnce() -> String) {
+        self.checks += 1;
+        if !is_ok {
+            self.failures.push(failure());
+        }
+    }
+
+    fn check_unwind<F: FnOnce() -> bool + panic::UnwindSafe>(&mut self, check: F, failure: impl FnOnce() -> String) {
+        match panic::catch_unwind(check) {
+            Ok(is_ok) => self.check(is_ok, failure),
+            Err(_) => self.check(false, || format!("{} (panicked)", failure())),
+        }
+    }
+}
+
+pub fn self_test() -> SelfTestReport {
+    regression_corpus().iter().fold(SelfTestReport::default(), |mut report, case| {
+        let converted = panic::catch_unwind(|| Ru::try_from(case.ipa).map(|ru| (ru.to_string(), ru.render())));
+        report.check(converted.is_ok(), || format!("{:?}: conversion panicked", case.ipa));
+        report.check_unwind(|| case.check(), || format!("{:?}: expected {:?}", case.ipa, case.expected));
+        if let Ok(Ok((displayed, rendered))) = converted {
+            report.check(rendered.as_ref() == Ok(&displayed), || {
+                format!("{:?}: Display {:?} differs from render {:?}", case.ipa, displayed, rendered)
+            });
+            report.check(unicode_normalization::is_nfc(&displayed), || {
+                format!("{:?}: output {:?} is not NFC", case.ipa, displayed)
+            });
+        }
+        report
+    })
+}
+
+#[cfg(test)]
+mod testkit_tests {
+    use super::*;
+
+    #[test]
+    fn test_regression_corpus() {
+        regression_corpus().iter().for_each(|case| assert!(case.check(), "{:?}", case));
+    }
+
+    #[test]
+    fn test_self_test() {
+        let report = self_test();
+        assert!(report.is_ok(), "{:?}", report.failures);
+        assert!(report.checks >= regression_corpus().len() * 2);
+    }
+
+    #[test]
+    fn test_check_unwind() {
+        let mut report = SelfTestReport::default();
+        report.check_unwind(|| true, || "ok".to_owned());
+        report.check_unwind(|| panic!("check"), || "panics".to_owned());
+        assert_eq!(report.checks, 2);
+        assert_eq!(report.failures, vec!["panics (panicked)".to_owned()]);
+    }
+}
@@ -0,0 +1,133 @@
+use core::fmt;
+use alloc::{borrow::ToOwned, string::String};
+
+use crate::error::Error;
+use crate::input::{self, Token};
+use crate::options::RuOptions;
+use crate::ru::Ru;
+
+#[derive(Clone)]
+pub struct RuWord {
+    offset: usize,
+    ipa: String,
+    ru: Ru,
+}
+
+impl RuWord {
+    pub(crate) fn convert(offset: usize, ipa: &str, options: RuOptions) -> Result<Self, Error> {
+        Ru::try_from_ipa_with(ipa, options).map(|ru| Self { offset, ipa: ipa.to_owned(), ru })
+    }
+
+    pub(crate) fn from_ru(offset: usize, ru: Ru) -> Self {
+        Self { offset, ipa: ru.ipa().to_owned(), ru }
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn ipa(&self) -> &str {
+        &self.ipa
+    }
+
+    pub fn ru(&self) -> &Ru {
+        &self.ru
+    }
+
+    pub fn into_ru(self) -> Ru {
+        self.ru
+    }
+
+    pub fn capitalized(self) -> Self {
+        let options = RuOptions { capitalize_words: true, ..self.ru.options().clone() };
+        Self { ru: self.ru.with_options(options), ..self }
+    }
+}
+
+impl fmt::Display for RuWord {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.ru, formatter)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RuWords<'a> {
+    input: &'a str,
+    offset: usize,
+    options: RuOptions,
+}
+
+impl<'a> RuWords<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self::with_options(input, RuOptions::default())
+    }
+
+    pub fn with_options(input: &'a str, options: RuOptions) -> Self {
+        Self { input, offset: 0, options }
+    }
+}
+
+impl<'a> Iterator for RuWords<'a> {
+    type Item = Result<RuWord, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.input.get(self.offset..)?;
+        let mut tokens = input::tokenize(rest, &self.options)
+            .scan(0, |end, token| {
+                let start = *end;
+                *end += token.as_str().len();
+                Some((start, *end, token))
+            });
+        let (start, end) = tokens.by_ref()
+            .find(|(_, _, token)| !matches!(token, Token::Separator(_)))
+            .map(|(start, end, _)| (start, end))?;
+        let end = tokens
+            .take_while(|(_, _, token)| !matches!(token, Token::Separator(_)))
+            .last()
+            .map_or(end, |(_, end, _)| end);
+        let ipa = &rest[start..end];
+        let offset = self.offset + start;
+        self.offset += end;
+        Some(RuWord::convert(offset, ipa, self.options.clone()))
+    }
+}
+
+#[cfg(test)]
+mod words_tests {
+    use super::*;
+
+    #[test]
+    fn test_words() {
+        let words = RuWords::new(" nʲæ\t mʲæ+u\r\npæp ")
+            .map(|word| word.map(|word| (word.offset(), word.ipa().to_owned(), word.to_string())))
+            .collect::<Vec<_>>();
+        assert_eq!(words, vec![
+            Ok((1, "nʲæ".to_owned(), "ня".to_owned())),
+            Ok((8, "mʲæ+u".to_owned(), "мяу".to_owned())),
+            Ok((17, "pæp".to_owned(), "пап".to_owned())),
+        ]);
+    }
+
+    #[test]
+    fn test_word_errors() {
+        let words = RuWords::new("nʲæ ʘ nʲæ").map(|word| word.map(|word| word.to_string())).collect::<Vec<_>>();
+        assert_eq!(words.len(), 3);
+        assert_eq!(words[0], Ok("ня".to_owned()));
+        assert!(words[1].is_err());
+        assert_eq!(words[2], Ok("ня".to_owned()));
+    }
+
+    #[test]
+    fn test_capitalized() {
+        let words = RuWords::new("nʲæ mæ")
+            .enumerate()
+            .map(|(i, word)| word.map(|word| if i == 0 { word.capitalized() } else { word }.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(words, vec![Ok("Ня".to_owned()), Ok("ма".to_owned())]);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(RuWords::new(" \t ").count(), 0);
+    }
+}